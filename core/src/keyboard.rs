@@ -1,8 +1,12 @@
 //! Reuse basic keyboard types.
 mod event;
 mod key_code;
+mod keymap;
 mod modifiers;
+mod shortcut;
 
 pub use event::Event;
 pub use key_code::KeyCode;
+pub use keymap::Keymap;
 pub use modifiers::Modifiers;
+pub use shortcut::Shortcut;
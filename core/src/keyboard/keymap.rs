@@ -0,0 +1,278 @@
+use crate::keyboard::{KeyCode, Modifiers, Shortcut};
+
+/// A set of actions of an application bound to keyboard [`Shortcut`]s.
+///
+/// Every action has at most one [`Shortcut`], and every [`Shortcut`] triggers
+/// at most one action. A [`Keymap`] keeps the order in which its actions are
+/// bound, so it can be listed as is in a shortcut editor.
+///
+/// Deserializing a [`Keymap`] fails if it binds the same action or the same
+/// [`Shortcut`] more than once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        crate = "serde",
+        try_from = "Bindings<A>",
+        bound(deserialize = "A: serde::Deserialize<'de> + PartialEq")
+    )
+)]
+pub struct Keymap<A> {
+    bindings: Vec<(A, Option<Shortcut>)>,
+}
+
+impl<A: PartialEq> Keymap<A> {
+    /// Creates an empty [`Keymap`].
+    pub fn new() -> Self {
+        Keymap {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Binds the given action to the given [`Shortcut`], if any, and returns
+    /// the [`Keymap`].
+    ///
+    /// This is useful to build the default [`Keymap`] of an application.
+    pub fn with(
+        mut self,
+        action: A,
+        shortcut: impl Into<Option<Shortcut>>,
+    ) -> Self {
+        match shortcut.into() {
+            Some(shortcut) => {
+                let _ = self.bind(action, shortcut);
+            }
+            None => {
+                let _ = self.unbind(action);
+            }
+        }
+
+        self
+    }
+
+    /// Binds the given action to the given [`Shortcut`].
+    ///
+    /// If the [`Shortcut`] was bound to a different action, that action is
+    /// left without a [`Shortcut`] and is returned.
+    pub fn bind(&mut self, action: A, shortcut: Shortcut) -> Option<&A> {
+        let conflict = self.bindings.iter().position(|(other, bound)| {
+            *other != action && *bound == Some(shortcut)
+        });
+
+        if let Some(index) = conflict {
+            self.bindings[index].1 = None;
+        }
+
+        let _ = self.set(action, Some(shortcut));
+
+        conflict.map(move |index| &self.bindings[index].0)
+    }
+
+    /// Leaves the given action without a [`Shortcut`], returning the previous
+    /// one.
+    pub fn unbind(&mut self, action: A) -> Option<Shortcut> {
+        self.set(action, None)
+    }
+
+    /// Returns the [`Shortcut`] of the given action, if any.
+    pub fn shortcut(&self, action: &A) -> Option<Shortcut> {
+        self.bindings
+            .iter()
+            .find(|(other, _)| other == action)
+            .and_then(|(_, shortcut)| *shortcut)
+    }
+
+    /// Returns the action triggered by pressing the given key with the given
+    /// [`Modifiers`], if any.
+    pub fn action(
+        &self,
+        key_code: KeyCode,
+        modifiers: Modifiers,
+    ) -> Option<&A> {
+        self.bindings
+            .iter()
+            .find(|(_, shortcut)| {
+                shortcut
+                    .map(|shortcut| shortcut.matches(key_code, modifiers))
+                    .unwrap_or(false)
+            })
+            .map(|(action, _)| action)
+    }
+
+    /// Returns an iterator over the actions of the [`Keymap`] and their
+    /// [`Shortcut`], in the order they were first bound.
+    pub fn iter(&self) -> impl Iterator<Item = (&A, Option<Shortcut>)> {
+        self.bindings
+            .iter()
+            .map(|(action, shortcut)| (action, *shortcut))
+    }
+
+    fn set(
+        &mut self,
+        action: A,
+        shortcut: Option<Shortcut>,
+    ) -> Option<Shortcut> {
+        match self.bindings.iter_mut().find(|(other, _)| *other == action) {
+            Some((_, bound)) => std::mem::replace(bound, shortcut),
+            None => {
+                self.bindings.push((action, shortcut));
+
+                None
+            }
+        }
+    }
+}
+
+impl<A: PartialEq> Default for Keymap<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The bindings of a [`Keymap`] that has not been validated yet.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(crate = "serde")]
+struct Bindings<A> {
+    bindings: Vec<(A, Option<Shortcut>)>,
+}
+
+#[cfg(feature = "serde")]
+impl<A: PartialEq> std::convert::TryFrom<Bindings<A>> for Keymap<A> {
+    type Error = &'static str;
+
+    fn try_from(
+        Bindings { bindings }: Bindings<A>,
+    ) -> Result<Self, Self::Error> {
+        for (index, (action, shortcut)) in bindings.iter().enumerate() {
+            let previous = &bindings[..index];
+
+            if previous.iter().any(|(other, _)| other == action) {
+                return Err("an action is bound more than once");
+            }
+
+            if shortcut.is_some()
+                && previous.iter().any(|(_, other)| other == shortcut)
+            {
+                return Err("a shortcut is bound to more than one action");
+            }
+        }
+
+        Ok(Keymap { bindings })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(crate = "serde")
+    )]
+    enum Action {
+        Play,
+        Pause,
+        Stop,
+    }
+
+    fn ctrl(key_code: KeyCode) -> Shortcut {
+        Shortcut::new(
+            key_code,
+            Modifiers {
+                control: true,
+                ..Modifiers::default()
+            },
+        )
+    }
+
+    fn keymap() -> Keymap<Action> {
+        Keymap::new()
+            .with(Action::Play, ctrl(KeyCode::P))
+            .with(Action::Pause, Shortcut::from(KeyCode::Space))
+            .with(Action::Stop, None)
+    }
+
+    #[test]
+    fn bind_returns_conflicting_action() {
+        let mut keymap = keymap();
+
+        assert_eq!(
+            keymap.bind(Action::Stop, ctrl(KeyCode::P)),
+            Some(&Action::Play)
+        );
+        assert_eq!(keymap.shortcut(&Action::Stop), Some(ctrl(KeyCode::P)));
+        assert_eq!(keymap.shortcut(&Action::Play), None);
+        assert_eq!(
+            keymap.action(KeyCode::P, ctrl(KeyCode::P).modifiers),
+            Some(&Action::Stop)
+        );
+    }
+
+    #[test]
+    fn bind_keeps_one_shortcut_per_action() {
+        let mut keymap = keymap();
+
+        assert_eq!(keymap.bind(Action::Play, ctrl(KeyCode::Enter)), None);
+        assert_eq!(keymap.bind(Action::Play, ctrl(KeyCode::Enter)), None);
+
+        assert_eq!(keymap.shortcut(&Action::Play), Some(ctrl(KeyCode::Enter)));
+        assert_eq!(keymap.action(KeyCode::P, ctrl(KeyCode::P).modifiers), None);
+    }
+
+    #[test]
+    fn iter_keeps_binding_order() {
+        let mut keymap = keymap();
+
+        let _ = keymap.bind(Action::Stop, ctrl(KeyCode::P));
+        let _ = keymap.unbind(Action::Pause);
+
+        assert_eq!(
+            keymap.iter().collect::<Vec<_>>(),
+            vec![
+                (&Action::Play, None),
+                (&Action::Pause, None),
+                (&Action::Stop, Some(ctrl(KeyCode::P))),
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_round_trips() {
+        let keymap = keymap();
+
+        let json = serde_json::to_string(&keymap).expect("Serialize keymap");
+
+        assert_eq!(
+            serde_json::from_str::<Keymap<Action>>(&json)
+                .expect("Deserialize keymap"),
+            keymap
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_rejects_duplicates() {
+        let space = serde_json::to_string(&Shortcut::from(KeyCode::Space))
+            .expect("Serialize shortcut");
+
+        let duplicate_action =
+            format!(r#"{{"bindings":[["Play",{0}],["Play",null]]}}"#, space);
+        let duplicate_shortcut = format!(
+            r#"{{"bindings":[["Play",{0}],["Pause",{0}],["Stop",null]]}}"#,
+            space
+        );
+        let unbound = r#"{"bindings":[["Play",null],["Pause",null]]}"#;
+
+        assert!(
+            serde_json::from_str::<Keymap<Action>>(&duplicate_action).is_err()
+        );
+        assert!(serde_json::from_str::<Keymap<Action>>(&duplicate_shortcut)
+            .is_err());
+        assert!(serde_json::from_str::<Keymap<Action>>(unbound).is_ok());
+    }
+}
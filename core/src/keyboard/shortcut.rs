@@ -0,0 +1,89 @@
+use crate::keyboard::{KeyCode, Modifiers};
+
+use std::fmt;
+
+/// A key combined with some [`Modifiers`], like `Ctrl+S`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+pub struct Shortcut {
+    /// The key of the [`Shortcut`].
+    pub key_code: KeyCode,
+
+    /// The modifiers that must be pressed together with the key.
+    pub modifiers: Modifiers,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] with the given key and [`Modifiers`].
+    pub fn new(key_code: KeyCode, modifiers: Modifiers) -> Self {
+        Shortcut {
+            key_code,
+            modifiers,
+        }
+    }
+
+    /// Returns true if pressing the given key with the given [`Modifiers`]
+    /// triggers the [`Shortcut`].
+    ///
+    /// The [`Modifiers`] must match exactly, so `Ctrl+Shift+S` does not
+    /// trigger `Ctrl+S`.
+    pub fn matches(&self, key_code: KeyCode, modifiers: Modifiers) -> bool {
+        self.key_code == key_code && self.modifiers == modifiers
+    }
+
+    /// Returns true if the given key is a modifier key.
+    ///
+    /// Modifier keys cannot be the key of a [`Shortcut`] on their own.
+    pub fn is_modifier(key_code: KeyCode) -> bool {
+        matches!(
+            key_code,
+            KeyCode::LShift
+                | KeyCode::RShift
+                | KeyCode::LControl
+                | KeyCode::RControl
+                | KeyCode::LAlt
+                | KeyCode::RAlt
+                | KeyCode::LWin
+                | KeyCode::RWin
+        )
+    }
+}
+
+impl From<KeyCode> for Shortcut {
+    fn from(key_code: KeyCode) -> Self {
+        Shortcut::new(key_code, Modifiers::default())
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(target_os = "macos")]
+        const LOGO: &str = "Cmd";
+
+        #[cfg(not(target_os = "macos"))]
+        const LOGO: &str = "Super";
+
+        let modifiers = [
+            (self.modifiers.control, "Ctrl"),
+            (self.modifiers.alt, "Alt"),
+            (self.modifiers.shift, "Shift"),
+            (self.modifiers.logo, LOGO),
+        ];
+
+        for (_, name) in modifiers.iter().filter(|(pressed, _)| *pressed) {
+            write!(f, "{}+", name)?;
+        }
+
+        let key = format!("{:?}", self.key_code);
+
+        // The digits over the letters are named `Key0` to `Key9`
+        match key.strip_prefix("Key") {
+            Some(digit) if digit.len() == 1 => write!(f, "{}", digit),
+            _ => write!(f, "{}", key),
+        }
+    }
+}
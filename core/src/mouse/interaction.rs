@@ -11,6 +11,13 @@ pub enum Interaction {
     Grabbing,
     ResizingHorizontally,
     ResizingVertically,
+    NotAllowed,
+    Move,
+    ZoomIn,
+    ZoomOut,
+    Help,
+    /// The cursor is not shown.
+    Hidden,
 }
 
 impl Default for Interaction {
@@ -0,0 +1,125 @@
+//! Cancel commands and subscriptions that are no longer needed.
+use futures::channel::oneshot;
+use futures::future::{FutureExt, Shared};
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::{Arc, Mutex};
+
+/// A token that can be used to cancel a [`Command`] or a [`Subscription`].
+///
+/// You should keep a [`Token`] in the state of your application and attach it
+/// to your work with [`Command::cancellable`] or
+/// [`Subscription::cancellable`]. Cancelling the [`Token`] will stop any
+/// attached work as soon as possible.
+///
+/// Cloning a [`Token`] produces a new handle to the same token. Dropping
+/// every handle of a [`Token`] cancels it as well, so work does not outlive
+/// the state that could cancel it.
+///
+/// [`Command`]: crate::Command
+/// [`Subscription`]: crate::Subscription
+/// [`Command::cancellable`]: crate::Command::cancellable
+/// [`Subscription::cancellable`]: crate::Subscription::cancellable
+#[derive(Debug, Clone)]
+pub struct Token {
+    id: u64,
+    sender: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+    receiver: Shared<oneshot::Receiver<()>>,
+}
+
+impl Token {
+    /// Creates a new [`Token`].
+    pub fn new() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        let (sender, receiver) = oneshot::channel();
+
+        Token {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            sender: Arc::new(Mutex::new(Some(sender))),
+            receiver: receiver.shared(),
+        }
+    }
+
+    /// Returns the unique identifier of the [`Token`].
+    ///
+    /// A [`Subscription`] attached to a [`Token`] is identified by it.
+    /// Therefore, replacing the [`Token`] of a [`Subscription`] will restart
+    /// it.
+    ///
+    /// [`Subscription`]: crate::Subscription
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Cancels the [`Token`], stopping any attached work.
+    pub fn cancel(&self) {
+        // Dropping the sender resolves every pending `cancelled` future
+        let _ = self.sender.lock().expect("Lock cancellation sender").take();
+    }
+
+    /// Returns whether the [`Token`] has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.sender
+            .lock()
+            .expect("Lock cancellation sender")
+            .is_none()
+    }
+
+    /// Returns a future that completes once the [`Token`] is cancelled.
+    pub fn cancelled(&self) -> impl std::future::Future<Output = ()> {
+        self.receiver.clone().map(|_| ())
+    }
+}
+
+impl Default for Token {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::FutureExt;
+
+    #[test]
+    fn cancel_resolves_every_handle() {
+        let token = Token::new();
+        let handle = token.clone();
+        let mut cancelled = Box::pin(handle.cancelled());
+
+        assert!(!handle.is_cancelled());
+        assert_eq!(cancelled.as_mut().now_or_never(), None);
+
+        token.cancel();
+
+        assert!(handle.is_cancelled());
+        assert_eq!(cancelled.now_or_never(), Some(()));
+        assert_eq!(handle.cancelled().now_or_never(), Some(()));
+    }
+
+    #[test]
+    fn dropping_every_handle_cancels() {
+        let token = Token::new();
+        let handle = token.clone();
+        let mut cancelled = Box::pin(token.cancelled());
+
+        drop(token);
+
+        assert!(!handle.is_cancelled());
+        assert_eq!(cancelled.as_mut().now_or_never(), None);
+
+        drop(handle);
+
+        assert_eq!(cancelled.now_or_never(), Some(()));
+    }
+
+    #[test]
+    fn tokens_are_unique() {
+        let token = Token::new();
+
+        assert_eq!(token.id(), token.clone().id());
+        assert_ne!(token.id(), Token::new().id());
+    }
+}
@@ -1,12 +1,14 @@
+use crate::cancellation;
 use crate::BoxFuture;
-use futures::future::{Future, FutureExt};
+use futures::future::{self, Either, Future, FutureExt};
+use std::task::Poll;
 
 /// A collection of async operations.
 ///
 /// You should be able to turn a future easily into a [`Command`], either by
 /// using the `From` trait or [`Command::perform`].
 pub struct Command<T> {
-    futures: Vec<BoxFuture<T>>,
+    futures: Vec<BoxFuture<Option<T>>>,
 }
 
 impl<T> Command<T> {
@@ -26,7 +28,7 @@ impl<T> Command<T> {
         f: impl Fn(T) -> A + 'static + Send,
     ) -> Command<A> {
         Command {
-            futures: vec![Box::pin(future.map(f))],
+            futures: vec![Box::pin(future.map(move |result| Some(f(result))))],
         }
     }
 
@@ -37,7 +39,7 @@ impl<T> Command<T> {
         f: impl Fn(T) -> A + 'static + Send,
     ) -> Command<A> {
         Command {
-            futures: vec![Box::pin(future.map(f))],
+            futures: vec![Box::pin(future.map(move |result| Some(f(result))))],
         }
     }
 
@@ -59,8 +61,8 @@ impl<T> Command<T> {
                 .map(|future| {
                     let f = f.clone();
 
-                    Box::pin(future.map(move |result| f(result)))
-                        as BoxFuture<A>
+                    Box::pin(future.map(move |result| result.map(|r| f(r))))
+                        as BoxFuture<Option<A>>
                 })
                 .collect(),
         }
@@ -81,8 +83,8 @@ impl<T> Command<T> {
                 .map(|future| {
                     let f = f.clone();
 
-                    Box::pin(future.map(move |result| f(result)))
-                        as BoxFuture<A>
+                    Box::pin(future.map(move |result| result.map(|r| f(r))))
+                        as BoxFuture<Option<A>>
                 })
                 .collect(),
         }
@@ -101,8 +103,105 @@ impl<T> Command<T> {
         }
     }
 
+    /// Makes the [`Command`] cancellable with the given [`Token`].
+    ///
+    /// Once the [`Token`] is cancelled, the futures of the [`Command`] will be
+    /// dropped and no result will be produced.
+    ///
+    /// [`Token`]: cancellation::Token
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn cancellable(self, token: &cancellation::Token) -> Self
+    where
+        T: 'static + Send,
+    {
+        Command {
+            futures: self
+                .futures
+                .into_iter()
+                .map(|future| {
+                    let cancelled = Box::pin(token.cancelled());
+
+                    // Cancellation is polled first, so it wins any tie
+                    future::select(cancelled, future)
+                        .map(|result| match result {
+                            Either::Left(_) => None,
+                            Either::Right((result, _)) => result,
+                        })
+                        .boxed()
+                })
+                .collect(),
+        }
+    }
+
+    /// Makes the [`Command`] cancellable with the given [`Token`].
+    ///
+    /// Once the [`Token`] is cancelled, the futures of the [`Command`] will be
+    /// dropped and no result will be produced.
+    ///
+    /// [`Token`]: cancellation::Token
+    #[cfg(target_arch = "wasm32")]
+    pub fn cancellable(self, token: &cancellation::Token) -> Self
+    where
+        T: 'static,
+    {
+        Command {
+            futures: self
+                .futures
+                .into_iter()
+                .map(|future| {
+                    let cancelled = Box::pin(token.cancelled());
+
+                    // Cancellation is polled first, so it wins any tie
+                    future::select(cancelled, future)
+                        .map(|result| match result {
+                            Either::Left(_) => None,
+                            Either::Right((result, _)) => result,
+                        })
+                        .boxed_local()
+                })
+                .collect(),
+        }
+    }
+
     /// Converts a [`Command`] into its underlying list of futures.
-    pub fn futures(self) -> Vec<BoxFuture<T>> {
+    ///
+    /// The futures of a cancelled [`Command`] never complete. A [`Runtime`]
+    /// drops them as soon as they are cancelled instead.
+    ///
+    /// [`Runtime`]: crate::Runtime
+    pub fn futures(self) -> Vec<BoxFuture<T>>
+    where
+        T: 'static,
+    {
+        self.futures
+            .into_iter()
+            .map(|future| {
+                let mut future = Some(future);
+
+                Box::pin(future::poll_fn(move |context| {
+                    let result = match future.as_mut() {
+                        Some(future) => future.as_mut().poll(context),
+                        None => return Poll::Pending,
+                    };
+
+                    match result {
+                        Poll::Ready(Some(result)) => Poll::Ready(result),
+                        Poll::Ready(None) => {
+                            // Cancelled futures must not be polled again
+                            future = None;
+
+                            Poll::Pending
+                        }
+                        Poll::Pending => Poll::Pending,
+                    }
+                })) as BoxFuture<T>
+            })
+            .collect()
+    }
+
+    /// Converts a [`Command`] into its underlying list of futures, which
+    /// produce `None` once cancelled.
+    pub(crate) fn into_futures(self) -> Vec<BoxFuture<Option<T>>> {
         self.futures
     }
 }
@@ -110,11 +209,12 @@ impl<T> Command<T> {
 #[cfg(not(target_arch = "wasm32"))]
 impl<T, A> From<A> for Command<T>
 where
+    T: 'static,
     A: Future<Output = T> + 'static + Send,
 {
     fn from(future: A) -> Self {
         Self {
-            futures: vec![future.boxed()],
+            futures: vec![future.map(Some).boxed()],
         }
     }
 }
@@ -122,11 +222,12 @@ where
 #[cfg(target_arch = "wasm32")]
 impl<T, A> From<A> for Command<T>
 where
+    T: 'static,
     A: Future<Output = T> + 'static,
 {
     fn from(future: A) -> Self {
         Self {
-            futures: vec![future.boxed_local()],
+            futures: vec![future.map(Some).boxed_local()],
         }
     }
 }
@@ -136,3 +237,56 @@ impl<T> std::fmt::Debug for Command<T> {
         f.debug_struct("Command").finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::channel::oneshot;
+
+    #[test]
+    fn cancellable_produces_nothing_once_cancelled() {
+        let token = cancellation::Token::new();
+        let (sender, receiver) = oneshot::channel::<u32>();
+
+        let mut futures =
+            Command::from(
+                async move { receiver.await.expect("Receive value") },
+            )
+            .cancellable(&token)
+            .into_futures();
+
+        let mut future = futures.remove(0);
+
+        assert_eq!(future.as_mut().now_or_never(), None);
+
+        token.cancel();
+        sender.send(42).expect("Send value");
+
+        assert_eq!(future.now_or_never(), Some(None));
+    }
+
+    #[test]
+    fn cancellable_completes_before_cancellation() {
+        let token = cancellation::Token::new();
+
+        let mut futures =
+            Command::from(async { 42 }).cancellable(&token).futures();
+
+        assert_eq!(futures.remove(0).now_or_never(), Some(42));
+    }
+
+    #[test]
+    fn cancelled_futures_never_complete() {
+        let token = cancellation::Token::new();
+        token.cancel();
+
+        let mut futures =
+            Command::from(async { 42 }).cancellable(&token).futures();
+
+        let mut future = futures.remove(0);
+
+        assert_eq!(future.as_mut().now_or_never(), None);
+        assert_eq!(future.now_or_never(), None);
+    }
+}
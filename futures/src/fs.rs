@@ -0,0 +1,231 @@
+//! Listen and react to changes in the file system.
+use crate::subscription::{self, Subscription};
+use crate::BoxStream;
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+/// A change in the file system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// A file or directory was created.
+    Created(PathBuf),
+
+    /// A file or directory was removed.
+    Removed(PathBuf),
+
+    /// The contents or metadata of a file or directory were modified.
+    Modified(PathBuf),
+}
+
+/// Returns a [`Subscription`] that produces a [`Change`] every time a file
+/// or directory under the given `path` is created, removed or modified.
+///
+/// Directories are watched recursively. Renames are reported as a
+/// [`Change::Removed`] of the old path followed by a [`Change::Created`] of
+/// the new one.
+///
+/// Any other `path` is watched through its parent directory, so it keeps
+/// being watched after it is replaced; like editors do when saving a file
+/// atomically.
+pub fn watch_path<H: std::hash::Hasher, E>(
+    path: impl Into<PathBuf>,
+) -> Subscription<H, E, Change> {
+    Subscription::from_recipe(WatchPath(path.into()))
+}
+
+/// Returns a [`Subscription`] that produces the given `path` every time the
+/// file at that path is written.
+///
+/// Like [`watch_path`], it watches the parent directory of the file. Unlike
+/// [`watch_path`], it ignores the file being removed. Therefore, replacing
+/// the file atomically only produces the `path` once the new contents are in
+/// place.
+pub fn watch_file<H: std::hash::Hasher, E>(
+    path: impl Into<PathBuf>,
+) -> Subscription<H, E, PathBuf> {
+    Subscription::from_recipe(WatchFile(path.into()))
+}
+
+impl Change {
+    /// Returns the path that changed.
+    pub fn path(&self) -> &Path {
+        match self {
+            Change::Created(path)
+            | Change::Removed(path)
+            | Change::Modified(path) => path,
+        }
+    }
+}
+
+struct WatchPath(PathBuf);
+
+impl<H, E> subscription::Recipe<H, E> for WatchPath
+where
+    H: std::hash::Hasher,
+{
+    type Output = Change;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.0.hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<E>) -> BoxStream<Change> {
+        watch(self.0)
+    }
+}
+
+struct WatchFile(PathBuf);
+
+impl<H, E> subscription::Recipe<H, E> for WatchFile
+where
+    H: std::hash::Hasher,
+{
+    type Output = PathBuf;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.0.hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<E>) -> BoxStream<PathBuf> {
+        use futures::future;
+        use futures::stream::StreamExt;
+
+        watch(self.0)
+            .filter_map(|change| {
+                future::ready(match change {
+                    Change::Created(path) | Change::Modified(path) => {
+                        Some(path)
+                    }
+                    Change::Removed(_) => None,
+                })
+            })
+            .boxed()
+    }
+}
+
+/// Watches the given `path`, through its parent directory unless it is a
+/// directory itself.
+fn watch(path: PathBuf) -> BoxStream<Change> {
+    use futures::channel::mpsc;
+    use futures::stream::{self, StreamExt};
+    use notify::Watcher;
+
+    let (directory, mode, file_name) = if path.is_dir() {
+        (path.clone(), notify::RecursiveMode::Recursive, None)
+    } else {
+        let directory = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => {
+                parent.to_path_buf()
+            }
+            _ => PathBuf::from("."),
+        };
+
+        (
+            directory,
+            notify::RecursiveMode::NonRecursive,
+            path.file_name().map(OsString::from),
+        )
+    };
+
+    let (sender, receiver) = mpsc::unbounded();
+
+    let watcher = notify::recommended_watcher(
+        move |result: notify::Result<notify::Event>| match result {
+            Ok(event) => {
+                for change in changes(event) {
+                    if is_watched(&change, file_name.as_deref()) {
+                        let _ = sender.unbounded_send(change);
+                    }
+                }
+            }
+            Err(error) => {
+                log::warn!("File system watcher error: {}", error);
+            }
+        },
+    )
+    .and_then(|mut watcher| {
+        watcher.watch(&directory, mode)?;
+
+        Ok(watcher)
+    });
+
+    match watcher {
+        Ok(watcher) => stream::unfold(
+            (watcher, receiver),
+            |(watcher, mut receiver)| async move {
+                let change = receiver.next().await?;
+
+                Some((change, (watcher, receiver)))
+            },
+        )
+        .boxed(),
+        Err(error) => {
+            log::warn!("Could not watch {}: {}", path.display(), error);
+
+            stream::empty().boxed()
+        }
+    }
+}
+
+/// Returns whether the [`Change`] concerns the watched file, if any.
+fn is_watched(change: &Change, file_name: Option<&OsStr>) -> bool {
+    match file_name {
+        Some(file_name) => change.path().file_name() == Some(file_name),
+        None => true,
+    }
+}
+
+fn changes(event: notify::Event) -> Vec<Change> {
+    use notify::event::{EventKind, ModifyKind, RenameMode};
+
+    let paths = event.paths.into_iter();
+
+    match event.kind {
+        EventKind::Create(_) => paths.map(Change::Created).collect(),
+        EventKind::Remove(_) => paths.map(Change::Removed).collect(),
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+            paths.map(Change::Removed).collect()
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+            paths.map(Change::Created).collect()
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+            // Backends reporting both paths at once also report each side
+            // separately.
+            Vec::new()
+        }
+        EventKind::Modify(ModifyKind::Name(_)) => paths
+            .map(|path| {
+                if path.exists() {
+                    Change::Created(path)
+                } else {
+                    Change::Removed(path)
+                }
+            })
+            .collect(),
+        EventKind::Modify(_) => paths.map(Change::Modified).collect(),
+        EventKind::Access(_) | EventKind::Any | EventKind::Other => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_watched_file_is_reported() {
+        let theme = Change::Modified(PathBuf::from("themes/dark.ron"));
+        let temporary = Change::Created(PathBuf::from("themes/.dark.ron.swp"));
+
+        assert!(is_watched(&theme, Some(OsStr::new("dark.ron"))));
+        assert!(!is_watched(&temporary, Some(OsStr::new("dark.ron"))));
+        assert!(is_watched(&temporary, None));
+    }
+}
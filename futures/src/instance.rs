@@ -0,0 +1,782 @@
+//! Enforce a single running instance of your application.
+//!
+//! When an application is started, it can try to become the primary
+//! [`Instance`] of a given identifier. If another process already is, the
+//! command-line arguments of the new process are forwarded to it and the new
+//! process should exit right away.
+//!
+//! The primary [`Instance`] receives the forwarded arguments through the
+//! [`Subscription`] of its [`Listener`].
+//!
+//! Instances communicate through a Unix domain socket on Unix platforms. The
+//! socket lives in `$XDG_RUNTIME_DIR` when it is set, or in the temporary
+//! directory under a name that includes the user id otherwise, so different
+//! users can run their own instance.
+//!
+//! Elsewhere, instances communicate through a loopback TCP port chosen by the
+//! primary instance. The port is written, along with a pair of random keys,
+//! to a file in the local application data directory of the user. Both ends
+//! of a connection present one of the keys, so only processes that can read
+//! the file of the user can reach the primary instance or pose as it.
+use crate::subscription::{self, Subscription};
+use crate::BoxStream;
+
+use futures::channel::mpsc;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The time an instance waits on a connection before giving up on it.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The outcome of trying to become the single running instance of an
+/// application.
+#[derive(Debug)]
+pub enum Instance {
+    /// This process is the primary instance.
+    ///
+    /// The [`Listener`] produces the arguments forwarded by any other process
+    /// started afterwards.
+    Primary(Listener),
+
+    /// Another process is the primary instance and has received the
+    /// arguments of this process. This process should exit.
+    Secondary,
+}
+
+impl Instance {
+    /// Tries to become the primary [`Instance`] for the given identifier,
+    /// forwarding the command-line arguments of the current process to the
+    /// primary one otherwise.
+    ///
+    /// Arguments naming an existing relative path are made absolute before
+    /// being forwarded, as the primary instance may be running in a different
+    /// directory.
+    ///
+    /// The identifier should be unique to your application, like
+    /// `"com.example.player"`.
+    pub fn acquire(id: &str) -> io::Result<Self> {
+        let args = std::env::args_os()
+            .skip(1)
+            .map(|arg| {
+                let path = std::path::Path::new(&arg);
+
+                if path.is_relative() && path.exists() {
+                    std::env::current_dir()
+                        .map(|directory| directory.join(path).into_os_string())
+                        .unwrap_or(arg)
+                } else {
+                    arg
+                }
+            })
+            .collect();
+
+        Self::acquire_with_args(id, args)
+    }
+
+    /// Tries to become the primary [`Instance`] for the given identifier,
+    /// forwarding the given arguments to the primary one otherwise.
+    pub fn acquire_with_args(
+        id: &str,
+        args: Vec<OsString>,
+    ) -> io::Result<Self> {
+        let (endpoint, keys) = platform::open(id)?;
+        let greeting = format!("iced-instance:{}:{}\n", id, keys.server);
+
+        match endpoint {
+            platform::Endpoint::Listener(listener) => {
+                let hub = Arc::new(Mutex::new(Hub::default()));
+
+                spawn_server(
+                    listener,
+                    greeting,
+                    keys.client,
+                    Arc::clone(&hub),
+                )?;
+
+                Ok(Instance::Primary(Listener { hub }))
+            }
+            platform::Endpoint::Connection(connection) => {
+                connection.set_read_timeout(Some(TIMEOUT))?;
+                connection.set_write_timeout(Some(TIMEOUT))?;
+
+                forward(connection, &greeting, &keys.client, &args)?;
+
+                Ok(Instance::Secondary)
+            }
+        }
+    }
+}
+
+/// The receiving end of the primary [`Instance`].
+#[derive(Debug, Clone)]
+pub struct Listener {
+    pub(crate) hub: Arc<Mutex<Hub>>,
+}
+
+impl Listener {
+    /// Returns a [`Subscription`] that produces the arguments forwarded by
+    /// every other process started with the same identifier.
+    ///
+    /// Arguments forwarded before the [`Subscription`] is started are kept
+    /// and produced once it starts.
+    pub fn forwarded<H, E>(&self) -> Subscription<H, E, Vec<OsString>>
+    where
+        H: std::hash::Hasher,
+    {
+        Subscription::from_recipe(Forwarded {
+            hub: Arc::clone(&self.hub),
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Hub {
+    pending: Vec<Vec<OsString>>,
+    subscribers: Vec<mpsc::UnboundedSender<Vec<OsString>>>,
+}
+
+impl Hub {
+    fn publish(&mut self, args: Vec<OsString>) {
+        self.subscribers.retain(|sender| !sender.is_closed());
+
+        if self.subscribers.is_empty() {
+            self.pending.push(args);
+        } else {
+            for sender in &self.subscribers {
+                let _ = sender.unbounded_send(args.clone());
+            }
+        }
+    }
+
+    pub(crate) fn subscribe(
+        &mut self,
+    ) -> mpsc::UnboundedReceiver<Vec<OsString>> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        for args in self.pending.drain(..) {
+            let _ = sender.unbounded_send(args);
+        }
+
+        self.subscribers.push(sender);
+
+        receiver
+    }
+}
+
+struct Forwarded {
+    hub: Arc<Mutex<Hub>>,
+}
+
+impl<H, E> subscription::Recipe<H, E> for Forwarded
+where
+    H: std::hash::Hasher,
+{
+    type Output = Vec<OsString>;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<E>,
+    ) -> BoxStream<Vec<OsString>> {
+        use futures::StreamExt;
+
+        self.hub
+            .lock()
+            .expect("Lock instance hub")
+            .subscribe()
+            .boxed()
+    }
+}
+
+/// The keys presented by both ends of a connection between instances.
+///
+/// The primary instance presents the server key in its greeting and other
+/// instances present the client key before their arguments. The keys are
+/// empty when the endpoint is already private to the current user.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Keys {
+    server: String,
+    client: String,
+}
+
+impl Keys {
+    #[cfg_attr(unix, allow(dead_code))]
+    fn generate() -> Self {
+        Keys {
+            server: random_key(),
+            client: random_key(),
+        }
+    }
+}
+
+#[cfg_attr(unix, allow(dead_code))]
+fn random_key() -> String {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    // The keys of every `RandomState` are derived from a seed provided by the
+    // operating system
+    (0..2)
+        .map(|_| format!("{:016x}", RandomState::new().build_hasher().finish()))
+        .collect()
+}
+
+/// An exclusive lock on the endpoint of an instance, held while a process
+/// decides whether it becomes the primary instance.
+///
+/// The lock is a file created next to the endpoint and removed on drop.
+#[derive(Debug)]
+struct Lock {
+    path: PathBuf,
+}
+
+impl Lock {
+    fn acquire(path: PathBuf) -> io::Result<Self> {
+        let start = Instant::now();
+
+        loop {
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(_) => return Ok(Lock { path }),
+                Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {
+                    // A process that stopped while holding the lock leaves
+                    // the file behind
+                    let is_stale = fs::metadata(&path)
+                        .and_then(|metadata| metadata.modified())
+                        .ok()
+                        .and_then(|modified| modified.elapsed().ok())
+                        .map_or(false, |age| age > TIMEOUT);
+
+                    if is_stale {
+                        let _ = fs::remove_file(&path);
+                    } else if start.elapsed() > TIMEOUT * 2 {
+                        return Err(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            "the instance lock was never released",
+                        ));
+                    } else {
+                        std::thread::sleep(Duration::from_millis(10));
+                    }
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+impl Drop for Lock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn spawn_server(
+    listener: platform::Listener,
+    greeting: String,
+    key: String,
+    hub: Arc<Mutex<Hub>>,
+) -> io::Result<()> {
+    let _ = std::thread::Builder::new()
+        .name(String::from("iced_futures::instance"))
+        .spawn(move || {
+            for connection in listener.incoming() {
+                // A connection that stalls must not keep the server from
+                // accepting the next one
+                let result = connection.and_then(|connection| {
+                    connection.set_read_timeout(Some(TIMEOUT))?;
+                    connection.set_write_timeout(Some(TIMEOUT))?;
+
+                    serve(connection, &greeting, &key)
+                });
+
+                match result {
+                    Ok(args) => {
+                        hub.lock().expect("Lock instance hub").publish(args)
+                    }
+                    Err(error) => {
+                        log::warn!("Invalid instance connection: {}", error)
+                    }
+                }
+            }
+        })?;
+
+    Ok(())
+}
+
+fn serve(
+    mut connection: impl Read + Write,
+    greeting: &str,
+    key: &str,
+) -> io::Result<Vec<OsString>> {
+    connection.write_all(greeting.as_bytes())?;
+    connection.flush()?;
+
+    let length = read_u32(&mut connection)?;
+    let mut received = Vec::new();
+
+    let _ = (&mut connection)
+        .take(u64::from(length))
+        .read_to_end(&mut received)?;
+
+    if received != key.as_bytes() {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "the connection did not present the key of the instance",
+        ));
+    }
+
+    receive(connection)
+}
+
+fn forward(
+    mut connection: impl Read + Write,
+    greeting: &str,
+    key: &str,
+    args: &[OsString],
+) -> io::Result<()> {
+    let mut received = vec![0; greeting.len()];
+    connection.read_exact(&mut received)?;
+
+    if received != greeting.as_bytes() {
+        return Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            "the instance address is used by another program",
+        ));
+    }
+
+    write_u32(&mut connection, key.len())?;
+    connection.write_all(key.as_bytes())?;
+
+    write_u32(&mut connection, args.len())?;
+
+    for arg in args {
+        let bytes = encode(arg);
+
+        write_u32(&mut connection, bytes.len())?;
+        connection.write_all(&bytes)?;
+    }
+
+    connection.flush()
+}
+
+fn receive(mut connection: impl Read) -> io::Result<Vec<OsString>> {
+    let count = read_u32(&mut connection)?;
+
+    (0..count)
+        .map(|_| {
+            let length = read_u32(&mut connection)?;
+            let mut arg = Vec::new();
+
+            let _ = (&mut connection)
+                .take(u64::from(length))
+                .read_to_end(&mut arg)?;
+
+            if arg.len() != length as usize {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "the connection closed in the middle of an argument",
+                ));
+            }
+
+            decode(arg)
+        })
+        .collect()
+}
+
+#[cfg(unix)]
+fn encode(arg: &std::ffi::OsStr) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+
+    arg.as_bytes().to_vec()
+}
+
+#[cfg(unix)]
+fn decode(bytes: Vec<u8>) -> io::Result<OsString> {
+    use std::os::unix::ffi::OsStringExt;
+
+    Ok(OsString::from_vec(bytes))
+}
+
+#[cfg(windows)]
+fn encode(arg: &std::ffi::OsStr) -> Vec<u8> {
+    use std::os::windows::ffi::OsStrExt;
+
+    arg.encode_wide().flat_map(u16::to_le_bytes).collect()
+}
+
+#[cfg(windows)]
+fn decode(bytes: Vec<u8>) -> io::Result<OsString> {
+    use std::os::windows::ffi::OsStringExt;
+
+    if bytes.len() % 2 != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "the argument is not valid UTF-16",
+        ));
+    }
+
+    let wide: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+
+    Ok(OsString::from_wide(&wide))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn encode(arg: &std::ffi::OsStr) -> Vec<u8> {
+    arg.to_string_lossy().into_owned().into_bytes()
+}
+
+#[cfg(not(any(unix, windows)))]
+fn decode(bytes: Vec<u8>) -> io::Result<OsString> {
+    String::from_utf8(bytes)
+        .map(OsString::from)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+fn write_u32(writer: &mut impl Write, value: usize) -> io::Result<()> {
+    use std::convert::TryFrom;
+
+    let value = u32::try_from(value)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+
+    writer.write_all(&value.to_be_bytes())
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(u32::from_be_bytes(bytes))
+}
+
+#[cfg(unix)]
+mod platform {
+    use super::{Keys, Lock};
+
+    use std::io;
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::PathBuf;
+
+    pub type Listener = UnixListener;
+
+    pub enum Endpoint {
+        Listener(UnixListener),
+        Connection(UnixStream),
+    }
+
+    pub fn path(id: &str) -> PathBuf {
+        let name: String = id
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        // The runtime directory is private to the current user, while the
+        // temporary directory is usually shared between every user
+        match std::env::var_os("XDG_RUNTIME_DIR")
+            .map(PathBuf::from)
+            .filter(|directory| directory.is_absolute())
+        {
+            Some(directory) => directory.join(format!("{}.sock", name)),
+            None => std::env::temp_dir().join(format!(
+                "{}-{}.sock",
+                name,
+                user_id()
+            )),
+        }
+    }
+
+    fn user_id() -> u32 {
+        use std::os::unix::fs::MetadataExt;
+
+        // Processes are owned by their user and the home directory is owned
+        // by the user on platforms without `/proc`
+        std::fs::metadata("/proc/self")
+            .or_else(|_| {
+                std::fs::metadata(std::env::var_os("HOME").unwrap_or_default())
+            })
+            .map(|metadata| metadata.uid())
+            .unwrap_or_default()
+    }
+
+    pub fn open(id: &str) -> io::Result<(Endpoint, Keys)> {
+        let path = path(id);
+
+        // Only the current user can reach the socket, so the keys are not
+        // needed
+        let keys = Keys::default();
+
+        // Processes that find a stale socket at the same time must not both
+        // replace it
+        let _lock = Lock::acquire(path.with_extension("lock"))?;
+
+        let endpoint = match UnixListener::bind(&path) {
+            Ok(listener) => Endpoint::Listener(listener),
+            Err(error) if error.kind() == io::ErrorKind::AddrInUse => {
+                match UnixStream::connect(&path) {
+                    Ok(connection) => Endpoint::Connection(connection),
+                    Err(error)
+                        if error.kind() == io::ErrorKind::ConnectionRefused =>
+                    {
+                        // The socket was left behind by a process that is
+                        // not running anymore
+                        std::fs::remove_file(&path)?;
+
+                        Endpoint::Listener(UnixListener::bind(&path)?)
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+            Err(error) => return Err(error),
+        };
+
+        Ok((endpoint, keys))
+    }
+}
+
+#[cfg(not(unix))]
+mod platform {
+    use super::{Keys, Lock, TIMEOUT};
+
+    use std::fs;
+    use std::io;
+    use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+    use std::path::PathBuf;
+
+    pub type Listener = TcpListener;
+
+    pub enum Endpoint {
+        Listener(TcpListener),
+        Connection(TcpStream),
+    }
+
+    pub fn path(id: &str) -> PathBuf {
+        let name: String = id
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        // The local application data directory and the profile directory are
+        // private to the current user
+        let directory = ["LOCALAPPDATA", "USERPROFILE", "HOME"]
+            .iter()
+            .filter_map(std::env::var_os)
+            .map(PathBuf::from)
+            .find(|directory| directory.is_absolute())
+            .unwrap_or_else(std::env::temp_dir);
+
+        directory.join(format!("{}.instance", name))
+    }
+
+    fn parse(contents: &str) -> Option<(SocketAddr, Keys)> {
+        let mut parts = contents.split_whitespace();
+
+        let port: u16 = parts.next()?.parse().ok()?;
+        let server = parts.next()?.to_owned();
+        let client = parts.next()?.to_owned();
+
+        Some((
+            SocketAddr::from((Ipv4Addr::LOCALHOST, port)),
+            Keys { server, client },
+        ))
+    }
+
+    pub fn open(id: &str) -> io::Result<(Endpoint, Keys)> {
+        let path = path(id);
+
+        // Processes started at the same time must not both become the
+        // primary instance
+        let _lock = Lock::acquire(path.with_extension("lock"))?;
+
+        let primary = fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| parse(&contents));
+
+        if let Some((address, keys)) = primary {
+            // The file may have been left behind by a process that is not
+            // running anymore
+            if let Ok(connection) =
+                TcpStream::connect_timeout(&address, TIMEOUT)
+            {
+                return Ok((Endpoint::Connection(connection), keys));
+            }
+        }
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        let port = listener.local_addr()?.port();
+        let keys = Keys::generate();
+
+        fs::write(
+            &path,
+            format!("{} {} {}\n", port, keys.server, keys.client),
+        )?;
+
+        Ok((Endpoint::Listener(listener), keys))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::StreamExt;
+
+    const GREETING: &str = "iced-instance:test:server\n";
+    const KEY: &str = "client";
+
+    fn args(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    #[cfg(unix)]
+    fn exchange(
+        greeting: &'static str,
+        key: &str,
+        args: &[OsString],
+    ) -> (io::Result<()>, io::Result<Vec<OsString>>) {
+        use std::os::unix::net::UnixStream;
+
+        let (server, client) = UnixStream::pair().expect("Create socket pair");
+        let server = std::thread::spawn(move || serve(server, greeting, KEY));
+
+        let forwarded = forward(client, GREETING, key, args);
+
+        (forwarded, server.join().expect("Join server"))
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn forwards_arguments_after_greeting() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let mut sent = args(&["--verbose", "/home/user/movie.mkv", ""]);
+        sent.push(OsString::from_vec(vec![b'f', 0xff, b'o']));
+
+        let (forwarded, received) = exchange(GREETING, KEY, &sent);
+
+        forwarded.expect("Forward arguments");
+        assert_eq!(received.expect("Receive arguments"), sent);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_unknown_greeting() {
+        let (forwarded, received) = exchange(
+            "some-other-program\n!!!!!!!!",
+            KEY,
+            &args(&["movie.mkv"]),
+        );
+
+        assert_eq!(
+            forwarded.expect_err("Reject greeting").kind(),
+            io::ErrorKind::AddrInUse
+        );
+        assert!(received.is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_unknown_key() {
+        let (_, received) = exchange(GREETING, "guess", &args(&["movie.mkv"]));
+
+        assert_eq!(
+            received.expect_err("Reject key").kind(),
+            io::ErrorKind::PermissionDenied
+        );
+    }
+
+    #[test]
+    fn lock_is_exclusive_until_dropped() {
+        use std::sync::mpsc;
+
+        let path = std::env::temp_dir()
+            .join(format!("iced-test-instance-{}.lock", std::process::id()));
+
+        let lock = Lock::acquire(path.clone()).expect("Acquire lock");
+        let (sender, receiver) = mpsc::channel();
+
+        let waiter = std::thread::spawn(move || {
+            let lock = Lock::acquire(path);
+            let _ = sender.send(());
+
+            lock
+        });
+
+        assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err());
+
+        drop(lock);
+
+        receiver
+            .recv_timeout(TIMEOUT)
+            .expect("Acquire released lock");
+        let _ = waiter.join().expect("Join waiter").expect("Acquire lock");
+    }
+
+    #[test]
+    fn generated_keys_differ() {
+        let keys = Keys::generate();
+
+        assert_eq!(keys.server.len(), 32);
+        assert_ne!(keys.server, keys.client);
+        assert_ne!(keys, Keys::generate());
+    }
+
+    #[test]
+    fn receive_round_trips_forwarded_bytes() {
+        let mut bytes = Vec::new();
+        write_u32(&mut bytes, 2).expect("Write count");
+
+        for arg in &["a", "bc"] {
+            write_u32(&mut bytes, arg.len()).expect("Write length");
+            bytes.extend_from_slice(arg.as_bytes());
+        }
+
+        assert_eq!(receive(&bytes[..]).expect("Receive"), args(&["a", "bc"]));
+    }
+
+    #[test]
+    fn receive_rejects_truncated_arguments() {
+        let bytes = [0, 0, 0, 1, 0, 0, 0, 4, b'a', b'b'];
+
+        assert_eq!(
+            receive(&bytes[..]).expect_err("Reject truncation").kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn secondary_instance_forwards_to_primary() {
+        let id = format!("iced.test.instance.{}", std::process::id());
+
+        let listener = match Instance::acquire_with_args(&id, Vec::new()) {
+            Ok(Instance::Primary(listener)) => listener,
+            other => panic!("Expected primary instance, got {:?}", other),
+        };
+
+        let sent = args(&["movie.mkv"]);
+
+        assert!(matches!(
+            Instance::acquire_with_args(&id, sent.clone()),
+            Ok(Instance::Secondary)
+        ));
+
+        let mut forwarded = listener.hub.lock().expect("Lock hub").subscribe();
+
+        assert_eq!(futures::executor::block_on(forwarded.next()), Some(sent));
+
+        let _ = std::fs::remove_file(platform::path(&id));
+    }
+}
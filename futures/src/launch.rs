@@ -0,0 +1,155 @@
+//! React to the files your application is opened with.
+//!
+//! Desktop environments open files with an application by passing their paths
+//! as command-line arguments. Combined with a single [`Instance`], the files
+//! opened while your application is already running are forwarded to it too.
+//!
+//! [`Instance`]: crate::instance::Instance
+use crate::instance;
+use crate::subscription::{self, Subscription};
+use crate::BoxStream;
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::{Arc, Mutex};
+
+/// Returns the files the current process was launched with.
+///
+/// Every command-line argument that does not start with a `-` is considered
+/// a file, and so is every argument after a `--`. Relative paths are resolved
+/// against the current directory.
+///
+/// The options of your application are told apart only by their leading `-`.
+/// Therefore, an option that takes a value must receive it in the same
+/// argument, like `--profile=work`. Otherwise, the value is considered a file.
+pub fn files() -> Vec<PathBuf> {
+    paths(std::env::args_os().skip(1))
+}
+
+/// Returns a [`Subscription`] that produces the path of every file your
+/// application is opened with.
+///
+/// The [`files`] of the current process are produced first, only once per
+/// process. Then, if a [`Listener`] is provided, the files forwarded by any
+/// other process started afterwards are produced as they arrive.
+///
+/// [`Listener`]: instance::Listener
+pub fn opened<H: std::hash::Hasher, E>(
+    listener: Option<&instance::Listener>,
+) -> Subscription<H, E, PathBuf> {
+    Subscription::from_recipe(Opened {
+        hub: listener.map(|listener| Arc::clone(&listener.hub)),
+    })
+}
+
+fn paths(args: impl IntoIterator<Item = impl Into<OsString>>) -> Vec<PathBuf> {
+    let directory = std::env::current_dir().unwrap_or_default();
+    let mut options_ended = false;
+
+    args.into_iter()
+        .map(Into::into)
+        .filter(|arg| {
+            if options_ended {
+                true
+            } else if arg == "--" {
+                options_ended = true;
+                false
+            } else {
+                !arg.to_string_lossy().starts_with('-')
+            }
+        })
+        .map(|arg| directory.join(arg))
+        .collect()
+}
+
+struct Opened {
+    hub: Option<Arc<Mutex<instance::Hub>>>,
+}
+
+impl<H, E> subscription::Recipe<H, E> for Opened
+where
+    H: std::hash::Hasher,
+{
+    type Output = PathBuf;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.hub.is_some().hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<E>) -> BoxStream<PathBuf> {
+        use futures::stream::{self, StreamExt};
+
+        static LAUNCHED: AtomicBool = AtomicBool::new(false);
+
+        let launch = if LAUNCHED.swap(true, atomic::Ordering::Relaxed) {
+            Vec::new()
+        } else {
+            files()
+        };
+
+        let forwarded = match self.hub {
+            Some(hub) => hub
+                .lock()
+                .expect("Lock instance hub")
+                .subscribe()
+                .flat_map(|args| stream::iter(paths(args)))
+                .boxed(),
+            None => stream::pending().boxed(),
+        };
+
+        stream::iter(launch).chain(forwarded).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn relative(args: &[&str]) -> Vec<PathBuf> {
+        let directory = std::env::current_dir().unwrap_or_default();
+
+        paths(args.iter().copied())
+            .into_iter()
+            .map(|path| {
+                path.strip_prefix(&directory)
+                    .map(PathBuf::from)
+                    .unwrap_or(path)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn skips_options() {
+        assert_eq!(
+            relative(&["-v", "movie.mkv", "--profile=work", "song.ogg"]),
+            vec![PathBuf::from("movie.mkv"), PathBuf::from("song.ogg")]
+        );
+    }
+
+    #[test]
+    fn keeps_every_argument_after_separator() {
+        assert_eq!(
+            relative(&["--fullscreen", "--", "-dash.mkv", "--", "movie.mkv"]),
+            vec![
+                PathBuf::from("-dash.mkv"),
+                PathBuf::from("--"),
+                PathBuf::from("movie.mkv")
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_relative_paths_against_current_directory() {
+        let directory = std::env::current_dir().unwrap_or_default();
+        let absolute = directory.join("movie.mkv");
+
+        assert_eq!(
+            paths(vec![OsString::from("movie.mkv"), absolute.clone().into()]),
+            vec![absolute.clone(), absolute]
+        );
+    }
+}
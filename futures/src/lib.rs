@@ -13,9 +13,23 @@ pub use futures;
 mod command;
 mod runtime;
 
+pub mod cancellation;
 pub mod executor;
 pub mod subscription;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod instance;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod launch;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod task;
+
+#[cfg(all(feature = "notify", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "notify")))]
+pub mod fs;
+
 #[cfg(all(
     any(feature = "tokio", feature = "tokio_old", feature = "async-std"),
     not(target_arch = "wasm32")
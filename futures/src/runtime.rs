@@ -54,15 +54,15 @@ where
     pub fn spawn(&mut self, command: Command<Message>) {
         use futures::{FutureExt, SinkExt};
 
-        let futures = command.futures();
+        let futures = command.into_futures();
 
         for future in futures {
             let mut sender = self.sender.clone();
 
             let future = future.then(|message| async move {
-                let _ = sender.send(message).await;
-
-                ()
+                if let Some(message) = message {
+                    let _ = sender.send(message).await;
+                }
             });
 
             self.executor.spawn(future);
@@ -3,6 +3,7 @@ mod tracker;
 
 pub use tracker::Tracker;
 
+use crate::cancellation;
 use crate::BoxStream;
 
 /// A request to listen to external events.
@@ -84,6 +85,119 @@ where
         }
     }
 
+    /// Makes the [`Subscription`] cancellable with the given [`Token`].
+    ///
+    /// The [`Token`] will be part of the identity of a [`Subscription`].
+    /// Once it is cancelled, the [`Subscription`] will stop producing values,
+    /// even if you keep returning it.
+    ///
+    /// [`Token`]: cancellation::Token
+    pub fn cancellable(mut self, token: &cancellation::Token) -> Self
+    where
+        H: 'static,
+        E: 'static,
+        O: 'static,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(Cancellable::new(recipe, token.clone()))
+                        as Box<dyn Recipe<H, E, Output = O>>
+                })
+                .collect(),
+        }
+    }
+
+    /// Batches the [`Subscription`] output, grouping the values produced
+    /// while the runtime is busy into a single `Vec`.
+    ///
+    /// Once a value is produced, the [`Subscription`] lets the other work of
+    /// the runtime run and keeps buffering values until no new ones arrive.
+    ///
+    /// This is useful for subscriptions that produce values faster than your
+    /// application can update, as a burst of values will only cause a single
+    /// message.
+    pub fn batched(mut self) -> Subscription<H, E, Vec<O>>
+    where
+        H: 'static,
+        E: 'static,
+        O: Send + 'static,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(Batched::new(recipe))
+                        as Box<dyn Recipe<H, E, Output = Vec<O>>>
+                })
+                .collect(),
+        }
+    }
+
+    /// Throttles the [`Subscription`], producing at most one output every
+    /// `duration`.
+    ///
+    /// The first output of a burst is produced right away. The latest output
+    /// produced while throttled is kept and produced once the `duration` has
+    /// elapsed, so the last value of a burst is never lost.
+    ///
+    /// The `duration` will be part of the identity of a [`Subscription`].
+    #[cfg(all(
+        any(feature = "tokio", feature = "tokio_old", feature = "async-std"),
+        not(target_arch = "wasm32")
+    ))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "tokio", feature = "async-std"))))]
+    pub fn throttle(mut self, duration: std::time::Duration) -> Self
+    where
+        H: 'static,
+        E: 'static,
+        O: Send + 'static,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(Throttle::new(recipe, duration))
+                        as Box<dyn Recipe<H, E, Output = O>>
+                })
+                .collect(),
+        }
+    }
+
+    /// Debounces the [`Subscription`], only producing its latest output once
+    /// no other output has been produced for the given `duration`.
+    ///
+    /// Any pending output is produced right away when the [`Subscription`]
+    /// ends.
+    ///
+    /// The `duration` will be part of the identity of a [`Subscription`].
+    #[cfg(all(
+        any(feature = "tokio", feature = "tokio_old", feature = "async-std"),
+        not(target_arch = "wasm32")
+    ))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "tokio", feature = "async-std"))))]
+    pub fn debounce(mut self, duration: std::time::Duration) -> Self
+    where
+        H: 'static,
+        E: 'static,
+        O: Send + 'static,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(Debounce::new(recipe, duration))
+                        as Box<dyn Recipe<H, E, Output = O>>
+                })
+                .collect(),
+        }
+    }
+
     /// Transforms the [`Subscription`] output with the given function.
     pub fn map<A>(mut self, f: fn(O) -> A) -> Subscription<H, E, A>
     where
@@ -230,3 +344,569 @@ where
         )
     }
 }
+
+struct Cancellable<Hasher, Event, A> {
+    recipe: Box<dyn Recipe<Hasher, Event, Output = A>>,
+    token: cancellation::Token,
+}
+
+impl<H, E, A> Cancellable<H, E, A> {
+    fn new(
+        recipe: Box<dyn Recipe<H, E, Output = A>>,
+        token: cancellation::Token,
+    ) -> Self {
+        Cancellable { recipe, token }
+    }
+}
+
+impl<H, E, A> Recipe<H, E> for Cancellable<H, E, A>
+where
+    A: 'static,
+    H: std::hash::Hasher,
+{
+    type Output = A;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.token.id().hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: BoxStream<E>) -> BoxStream<Self::Output> {
+        use futures::StreamExt;
+
+        Box::pin(
+            self.recipe
+                .stream(input)
+                .take_until(Box::pin(self.token.cancelled())),
+        )
+    }
+}
+
+struct Batched<Hasher, Event, A> {
+    recipe: Box<dyn Recipe<Hasher, Event, Output = A>>,
+}
+
+impl<H, E, A> Batched<H, E, A> {
+    fn new(recipe: Box<dyn Recipe<H, E, Output = A>>) -> Self {
+        Batched { recipe }
+    }
+}
+
+impl<H, E, A> Recipe<H, E> for Batched<H, E, A>
+where
+    A: Send + 'static,
+    H: std::hash::Hasher,
+{
+    type Output = Vec<A>;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: BoxStream<E>) -> BoxStream<Self::Output> {
+        use futures::stream::{self, StreamExt};
+        use futures::FutureExt;
+
+        stream::unfold(Some(self.recipe.stream(input)), |outputs| async move {
+            let mut outputs = outputs?;
+            let mut batch = vec![outputs.next().await?];
+
+            // Keep buffering outputs for as long as the rest of the runtime
+            // keeps producing them
+            loop {
+                let buffered = batch.len();
+
+                yield_now().await;
+
+                while let Some(output) = outputs.next().now_or_never() {
+                    match output {
+                        Some(output) => batch.push(output),
+                        None => return Some((batch, None)),
+                    }
+                }
+
+                if batch.len() == buffered {
+                    break;
+                }
+            }
+
+            Some((batch, Some(outputs)))
+        })
+        .boxed()
+    }
+}
+
+/// Lets every other task of the executor run once before resuming.
+async fn yield_now() {
+    let mut has_yielded = false;
+
+    futures::future::poll_fn(|context| {
+        if has_yielded {
+            std::task::Poll::Ready(())
+        } else {
+            has_yielded = true;
+            context.waker().wake_by_ref();
+
+            std::task::Poll::Pending
+        }
+    })
+    .await
+}
+
+#[cfg(all(
+    any(feature = "tokio", feature = "tokio_old", feature = "async-std"),
+    not(target_arch = "wasm32")
+))]
+struct Throttle<Hasher, Event, A> {
+    recipe: Box<dyn Recipe<Hasher, Event, Output = A>>,
+    duration: std::time::Duration,
+}
+
+#[cfg(all(
+    any(feature = "tokio", feature = "tokio_old", feature = "async-std"),
+    not(target_arch = "wasm32")
+))]
+impl<H, E, A> Throttle<H, E, A> {
+    fn new(
+        recipe: Box<dyn Recipe<H, E, Output = A>>,
+        duration: std::time::Duration,
+    ) -> Self {
+        Throttle { recipe, duration }
+    }
+}
+
+#[cfg(all(
+    any(feature = "tokio", feature = "tokio_old", feature = "async-std"),
+    not(target_arch = "wasm32")
+))]
+impl<H, E, A> Recipe<H, E> for Throttle<H, E, A>
+where
+    A: Send + 'static,
+    H: std::hash::Hasher,
+{
+    type Output = A;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.duration.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: BoxStream<E>) -> BoxStream<Self::Output> {
+        use futures::FutureExt;
+
+        throttle(self.recipe.stream(input), self.duration, |duration| {
+            crate::time::sleep(duration).boxed()
+        })
+    }
+}
+
+#[cfg(all(
+    any(feature = "tokio", feature = "tokio_old", feature = "async-std"),
+    not(target_arch = "wasm32")
+))]
+struct Debounce<Hasher, Event, A> {
+    recipe: Box<dyn Recipe<Hasher, Event, Output = A>>,
+    duration: std::time::Duration,
+}
+
+#[cfg(all(
+    any(feature = "tokio", feature = "tokio_old", feature = "async-std"),
+    not(target_arch = "wasm32")
+))]
+impl<H, E, A> Debounce<H, E, A> {
+    fn new(
+        recipe: Box<dyn Recipe<H, E, Output = A>>,
+        duration: std::time::Duration,
+    ) -> Self {
+        Debounce { recipe, duration }
+    }
+}
+
+#[cfg(all(
+    any(feature = "tokio", feature = "tokio_old", feature = "async-std"),
+    not(target_arch = "wasm32")
+))]
+impl<H, E, A> Recipe<H, E> for Debounce<H, E, A>
+where
+    A: Send + 'static,
+    H: std::hash::Hasher,
+{
+    type Output = A;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.duration.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: BoxStream<E>) -> BoxStream<Self::Output> {
+        use futures::FutureExt;
+
+        debounce(self.recipe.stream(input), self.duration, |duration| {
+            crate::time::sleep(duration).boxed()
+        })
+    }
+}
+
+/// Produces the first output right away and then, at most once per
+/// `duration`, the latest output produced in the meantime.
+#[cfg(any(
+    test,
+    all(
+        any(feature = "tokio", feature = "tokio_old", feature = "async-std"),
+        not(target_arch = "wasm32")
+    )
+))]
+fn throttle<A>(
+    outputs: BoxStream<A>,
+    duration: std::time::Duration,
+    sleep: impl Fn(std::time::Duration) -> crate::BoxFuture<()> + Send + 'static,
+) -> BoxStream<A>
+where
+    A: Send + 'static,
+{
+    use futures::future::{self, Either};
+    use futures::stream::{self, StreamExt};
+
+    stream::unfold(
+        (Some(outputs), None, None, sleep),
+        move |(mut outputs, mut window, mut trailing, sleep)| async move {
+            loop {
+                let mut current = outputs.take()?;
+
+                let open: crate::BoxFuture<()> = match window.take() {
+                    Some(open) => open,
+                    None => {
+                        let next = current.next().await?;
+                        let window = Some(sleep(duration));
+
+                        return Some((
+                            next,
+                            (Some(current), window, None, sleep),
+                        ));
+                    }
+                };
+
+                match future::select(current.next(), open).await {
+                    Either::Left((Some(next), open)) => {
+                        trailing = Some(next);
+                        window = Some(open);
+                    }
+                    Either::Left((None, _)) => {
+                        return trailing
+                            .take()
+                            .map(|latest| (latest, (None, None, None, sleep)));
+                    }
+                    Either::Right(_) => {
+                        if let Some(latest) = trailing.take() {
+                            let window = Some(sleep(duration));
+
+                            return Some((
+                                latest,
+                                (Some(current), window, None, sleep),
+                            ));
+                        }
+                    }
+                }
+
+                outputs = Some(current);
+            }
+        },
+    )
+    .boxed()
+}
+
+/// Produces the latest output once no other output has been produced for
+/// `duration`, or right away when the outputs end.
+#[cfg(any(
+    test,
+    all(
+        any(feature = "tokio", feature = "tokio_old", feature = "async-std"),
+        not(target_arch = "wasm32")
+    )
+))]
+fn debounce<A>(
+    outputs: BoxStream<A>,
+    duration: std::time::Duration,
+    sleep: impl Fn(std::time::Duration) -> crate::BoxFuture<()> + Send + 'static,
+) -> BoxStream<A>
+where
+    A: Send + 'static,
+{
+    use futures::future::{self, Either};
+    use futures::stream::{self, StreamExt};
+
+    stream::unfold(
+        (Some(outputs), None, sleep),
+        move |(mut outputs, mut pending, sleep)| async move {
+            loop {
+                let mut current = outputs.take()?;
+
+                match pending.take() {
+                    None => {
+                        pending = Some(current.next().await?);
+                        outputs = Some(current);
+                    }
+                    Some(latest) => {
+                        match future::select(current.next(), sleep(duration))
+                            .await
+                        {
+                            Either::Left((Some(next), _)) => {
+                                pending = Some(next);
+                                outputs = Some(current);
+                            }
+                            Either::Left((None, _)) => {
+                                return Some((latest, (None, None, sleep)));
+                            }
+                            Either::Right(_) => {
+                                return Some((
+                                    latest,
+                                    (Some(current), None, sleep),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::channel::{mpsc, oneshot};
+    use futures::stream::{self, StreamExt};
+    use futures::FutureExt;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher as _;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    struct Channel(mpsc::UnboundedReceiver<u32>);
+
+    impl Recipe<DefaultHasher, ()> for Channel {
+        type Output = u32;
+
+        fn hash(&self, state: &mut DefaultHasher) {
+            use std::hash::Hash;
+
+            "channel".hash(state);
+        }
+
+        fn stream(self: Box<Self>, _input: BoxStream<()>) -> BoxStream<u32> {
+            self.0.boxed()
+        }
+    }
+
+    fn channel() -> (
+        mpsc::UnboundedSender<u32>,
+        Subscription<DefaultHasher, (), u32>,
+    ) {
+        let (sender, receiver) = mpsc::unbounded();
+
+        (sender, Subscription::from_recipe(Channel(receiver)))
+    }
+
+    fn stream<O>(
+        subscription: Subscription<DefaultHasher, (), O>,
+    ) -> BoxStream<O> {
+        subscription
+            .recipes()
+            .remove(0)
+            .stream(stream::empty().boxed())
+    }
+
+    fn identity<O>(subscription: Subscription<DefaultHasher, (), O>) -> u64 {
+        let mut hasher = DefaultHasher::default();
+        subscription.recipes()[0].hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    #[test]
+    fn cancellable_stops_once_cancelled() {
+        let token = cancellation::Token::new();
+        let (sender, subscription) = channel();
+        let mut values = stream(subscription.cancellable(&token));
+
+        sender.unbounded_send(1).expect("Send value");
+        assert_eq!(values.next().now_or_never(), Some(Some(1)));
+
+        token.cancel();
+        sender.unbounded_send(2).expect("Send value");
+
+        assert_eq!(values.next().now_or_never(), Some(None));
+    }
+
+    #[test]
+    fn cancellable_is_identified_by_token() {
+        let token = cancellation::Token::new();
+
+        let first = identity(channel().1.cancellable(&token));
+        let second = identity(channel().1.cancellable(&token));
+        let other =
+            identity(channel().1.cancellable(&cancellation::Token::new()));
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_ne!(first, identity(channel().1));
+    }
+
+    /// A timer whose sleeps only finish when told to.
+    #[derive(Default, Clone)]
+    struct Timer {
+        alarms: Arc<Mutex<Vec<oneshot::Sender<()>>>>,
+    }
+
+    impl Timer {
+        fn sleep(
+            &self,
+        ) -> impl Fn(Duration) -> crate::BoxFuture<()> + Send + 'static
+        {
+            let alarms = self.alarms.clone();
+
+            move |_| {
+                let (sender, receiver) = oneshot::channel();
+                alarms.lock().expect("Lock alarms").push(sender);
+
+                receiver.map(|_| ()).boxed()
+            }
+        }
+
+        fn elapse(&self) {
+            for alarm in self.alarms.lock().expect("Lock alarms").drain(..) {
+                let _ = alarm.send(());
+            }
+        }
+    }
+
+    fn poll(values: &mut BoxStream<u32>) -> Option<Option<u32>> {
+        values.next().now_or_never()
+    }
+
+    #[test]
+    fn throttle_produces_leading_and_trailing_values() {
+        let timer = Timer::default();
+        let (sender, receiver) = mpsc::unbounded();
+        let mut values =
+            throttle(receiver.boxed(), Duration::from_secs(1), timer.sleep());
+
+        for value in 1..=3 {
+            sender.unbounded_send(value).expect("Send value");
+        }
+
+        assert_eq!(poll(&mut values), Some(Some(1)));
+        assert_eq!(poll(&mut values), None);
+
+        timer.elapse();
+
+        assert_eq!(poll(&mut values), Some(Some(3)));
+        assert_eq!(poll(&mut values), None);
+
+        // A quiet window goes back to producing values right away
+        timer.elapse();
+        assert_eq!(poll(&mut values), None);
+
+        sender.unbounded_send(4).expect("Send value");
+        assert_eq!(poll(&mut values), Some(Some(4)));
+    }
+
+    #[test]
+    fn throttle_flushes_trailing_value_when_ended() {
+        let timer = Timer::default();
+        let (sender, receiver) = mpsc::unbounded();
+        let mut values =
+            throttle(receiver.boxed(), Duration::from_secs(1), timer.sleep());
+
+        sender.unbounded_send(1).expect("Send value");
+        sender.unbounded_send(2).expect("Send value");
+        drop(sender);
+
+        assert_eq!(poll(&mut values), Some(Some(1)));
+        assert_eq!(poll(&mut values), Some(Some(2)));
+        assert_eq!(poll(&mut values), Some(None));
+    }
+
+    #[test]
+    fn debounce_produces_latest_value_once_quiet() {
+        let timer = Timer::default();
+        let (sender, receiver) = mpsc::unbounded();
+        let mut values =
+            debounce(receiver.boxed(), Duration::from_secs(1), timer.sleep());
+
+        sender.unbounded_send(1).expect("Send value");
+        sender.unbounded_send(2).expect("Send value");
+
+        assert_eq!(poll(&mut values), None);
+
+        sender.unbounded_send(3).expect("Send value");
+
+        assert_eq!(poll(&mut values), None);
+
+        timer.elapse();
+
+        assert_eq!(poll(&mut values), Some(Some(3)));
+        assert_eq!(poll(&mut values), None);
+    }
+
+    #[test]
+    fn debounce_flushes_pending_value_when_ended() {
+        let timer = Timer::default();
+        let (sender, receiver) = mpsc::unbounded();
+        let mut values =
+            debounce(receiver.boxed(), Duration::from_secs(1), timer.sleep());
+
+        sender.unbounded_send(1).expect("Send value");
+        sender.unbounded_send(2).expect("Send value");
+        drop(sender);
+
+        assert_eq!(poll(&mut values), Some(Some(2)));
+        assert_eq!(poll(&mut values), Some(None));
+    }
+
+    #[test]
+    fn batched_groups_values_produced_while_busy() {
+        use futures::executor::LocalPool;
+        use futures::task::LocalSpawnExt;
+
+        let mut pool = LocalPool::new();
+        let (sender, subscription) = channel();
+        let batches = stream(subscription.batched());
+
+        pool.spawner()
+            .spawn_local(async move {
+                for value in 0..10 {
+                    sender.unbounded_send(value).expect("Send value");
+
+                    yield_now().await;
+                }
+            })
+            .expect("Spawn producer");
+
+        let batches = pool.run_until(batches.collect::<Vec<_>>());
+
+        assert!(batches.len() < 10, "{:?}", batches);
+        assert_eq!(
+            batches.into_iter().flatten().collect::<Vec<_>>(),
+            (0..10).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,393 @@
+//! Run long-running jobs with progress reporting in a bounded pool.
+//!
+//! A job is an asynchronous function that receives a [`Reporter`] to notify
+//! its progress. Running a job in a [`Pool`] produces a [`Subscription`] of
+//! [`Update`] values, which you can map into messages of your application.
+//!
+//! Like any other [`Subscription`], a job keeps running as long as you keep
+//! returning it from your `subscription` logic. Stop returning it and the job
+//! will be cancelled, releasing its slot in the [`Pool`].
+use crate::subscription::{self, Subscription};
+use crate::{BoxFuture, BoxStream};
+
+use futures::channel::{mpsc, oneshot};
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+/// A pool that bounds the amount of jobs running concurrently.
+///
+/// Jobs started when the [`Pool`] is full stay queued until a running job
+/// finishes or is cancelled.
+///
+/// Cloning a [`Pool`] produces a new handle to the same pool.
+#[derive(Debug, Clone)]
+pub struct Pool {
+    slots: Arc<Mutex<Slots>>,
+}
+
+#[derive(Debug)]
+struct Slots {
+    available: usize,
+    waiting: VecDeque<oneshot::Sender<Slot>>,
+}
+
+impl Pool {
+    /// Creates a new [`Pool`] that runs at most `concurrency` jobs at the same
+    /// time.
+    ///
+    /// A `concurrency` of zero is treated as one.
+    pub fn new(concurrency: usize) -> Self {
+        Pool {
+            slots: Arc::new(Mutex::new(Slots {
+                available: concurrency.max(1),
+                waiting: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Runs a job in the [`Pool`] and returns a [`Subscription`] to its
+    /// [`Update`] values.
+    ///
+    /// The `id` identifies the job. As long as you keep returning a
+    /// [`Subscription`] with the same `id`, the job will not be restarted.
+    pub fn run<H, E, I, P, T, F>(
+        &self,
+        id: I,
+        job: impl FnOnce(Reporter<P>) -> F + Send + 'static,
+    ) -> Subscription<H, E, Update<P, T>>
+    where
+        H: std::hash::Hasher,
+        I: Hash + 'static,
+        P: Send + 'static,
+        T: Send + 'static,
+        F: std::future::Future<Output = T> + Send + 'static,
+    {
+        Subscription::from_recipe(Job {
+            id,
+            pool: self.clone(),
+            job: Box::new(move |reporter| Box::pin(job(reporter))),
+        })
+    }
+
+    async fn acquire(&self) -> Slot {
+        let receiver = {
+            let mut slots = self.slots.lock().expect("Lock pool slots");
+
+            if slots.available > 0 {
+                slots.available -= 1;
+
+                return Slot {
+                    pool: Some(self.clone()),
+                };
+            }
+
+            let (sender, receiver) = oneshot::channel();
+            slots.waiting.push_back(sender);
+
+            receiver
+        };
+
+        receiver.await.expect("Pool slot handed over")
+    }
+
+    fn release(&self) {
+        let mut slots = self.slots.lock().expect("Lock pool slots");
+
+        // Hand the slot over to the first queued job that is still alive
+        while let Some(sender) = slots.waiting.pop_front() {
+            match sender.send(Slot {
+                pool: Some(self.clone()),
+            }) {
+                Ok(()) => return,
+                Err(mut slot) => {
+                    // The slot is returned below, while we hold the lock
+                    slot.pool = None;
+                }
+            }
+        }
+
+        slots.available += 1;
+    }
+}
+
+impl Default for Pool {
+    /// Creates a [`Pool`] bounded by the available parallelism of the
+    /// system.
+    fn default() -> Self {
+        Pool::new(
+            std::thread::available_parallelism()
+                .map(usize::from)
+                .unwrap_or(1),
+        )
+    }
+}
+
+/// A running slot of a [`Pool`], released on drop.
+#[derive(Debug)]
+struct Slot {
+    pool: Option<Pool>,
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        if let Some(pool) = self.pool.take() {
+            pool.release();
+        }
+    }
+}
+
+/// A handle a job can use to report its progress.
+#[derive(Debug)]
+pub struct Reporter<P> {
+    sender: mpsc::UnboundedSender<P>,
+}
+
+impl<P> Reporter<P> {
+    /// Reports some progress of the job.
+    pub fn report(&self, progress: P) {
+        let _ = self.sender.unbounded_send(progress);
+    }
+}
+
+impl<P> Clone for Reporter<P> {
+    fn clone(&self) -> Self {
+        Reporter {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+/// The state of a job running in a [`Pool`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Update<P, T> {
+    /// The job is waiting for a slot in the [`Pool`].
+    Queued,
+
+    /// The job has started running.
+    Started,
+
+    /// The job has reported some progress.
+    Progressed(P),
+
+    /// The job has finished with the given output.
+    Finished(T),
+}
+
+struct Job<I, P, T> {
+    id: I,
+    pool: Pool,
+    job: Box<dyn FnOnce(Reporter<P>) -> BoxFuture<T> + Send>,
+}
+
+enum State<P, T> {
+    Idle {
+        pool: Pool,
+        job: Box<dyn FnOnce(Reporter<P>) -> BoxFuture<T> + Send>,
+    },
+    Queued {
+        pool: Pool,
+        job: Box<dyn FnOnce(Reporter<P>) -> BoxFuture<T> + Send>,
+    },
+    Running {
+        _slot: Slot,
+        progress: mpsc::UnboundedReceiver<P>,
+        output: BoxFuture<T>,
+    },
+    Finishing {
+        progress: mpsc::UnboundedReceiver<P>,
+        output: T,
+    },
+    Finished,
+}
+
+impl<H, E, I, P, T> subscription::Recipe<H, E> for Job<I, P, T>
+where
+    H: std::hash::Hasher,
+    I: Hash + 'static,
+    P: Send + 'static,
+    T: Send + 'static,
+{
+    type Output = Update<P, T>;
+
+    fn hash(&self, state: &mut H) {
+        std::any::TypeId::of::<Self>().hash(state);
+        self.id.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<E>,
+    ) -> BoxStream<Self::Output> {
+        use futures::future::{self, Either};
+        use futures::stream::{self, StreamExt};
+
+        let initial = State::Idle {
+            pool: self.pool,
+            job: self.job,
+        };
+
+        stream::unfold(initial, |state| async move {
+            match state {
+                State::Idle { pool, job } => {
+                    Some((Update::Queued, State::Queued { pool, job }))
+                }
+                State::Queued { pool, job } => {
+                    let slot = pool.acquire().await;
+                    let (sender, receiver) = mpsc::unbounded();
+
+                    Some((
+                        Update::Started,
+                        State::Running {
+                            _slot: slot,
+                            progress: receiver,
+                            output: job(Reporter { sender }),
+                        },
+                    ))
+                }
+                State::Running {
+                    _slot,
+                    mut progress,
+                    output,
+                } => match future::select(progress.next(), output).await {
+                    Either::Left((Some(value), output)) => Some((
+                        Update::Progressed(value),
+                        State::Running {
+                            _slot,
+                            progress,
+                            output,
+                        },
+                    )),
+                    Either::Left((None, output)) => {
+                        Some((Update::Finished(output.await), State::Finished))
+                    }
+                    Either::Right((output, _)) => {
+                        Some(finish(progress, output))
+                    }
+                },
+                State::Finishing { progress, output } => {
+                    Some(finish(progress, output))
+                }
+                State::Finished => None,
+            }
+        })
+        .boxed()
+    }
+}
+
+/// Reports any progress still buffered before finishing a job.
+fn finish<P, T>(
+    mut progress: mpsc::UnboundedReceiver<P>,
+    output: T,
+) -> (Update<P, T>, State<P, T>) {
+    use futures::{FutureExt, StreamExt};
+
+    match progress.next().now_or_never() {
+        Some(Some(value)) => (
+            Update::Progressed(value),
+            State::Finishing { progress, output },
+        ),
+        _ => (Update::Finished(output), State::Finished),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::future::{self, FutureExt};
+    use futures::stream::{self, StreamExt};
+    use std::collections::hash_map::DefaultHasher;
+
+    type Updates<T> = BoxStream<Update<u32, T>>;
+
+    fn run<T, F>(
+        pool: &Pool,
+        id: &'static str,
+        job: impl FnOnce(Reporter<u32>) -> F + Send + 'static,
+    ) -> Updates<T>
+    where
+        T: Send + 'static,
+        F: std::future::Future<Output = T> + Send + 'static,
+    {
+        let subscription: Subscription<DefaultHasher, (), _> =
+            pool.run(id, job);
+
+        subscription
+            .recipes()
+            .remove(0)
+            .stream(stream::empty().boxed())
+    }
+
+    fn poll<T>(updates: &mut Updates<T>) -> Option<Option<Update<u32, T>>> {
+        updates.next().now_or_never()
+    }
+
+    #[test]
+    fn reports_progress_in_order() {
+        let pool = Pool::new(1);
+
+        let updates = run(&pool, "job", |reporter| async move {
+            reporter.report(1);
+            reporter.report(2);
+
+            "done"
+        });
+
+        assert_eq!(
+            futures::executor::block_on(updates.collect::<Vec<_>>()),
+            vec![
+                Update::Queued,
+                Update::Started,
+                Update::Progressed(1),
+                Update::Progressed(2),
+                Update::Finished("done"),
+            ]
+        );
+    }
+
+    #[test]
+    fn queues_jobs_when_full() {
+        let pool = Pool::new(1);
+        let (sender, receiver) = oneshot::channel::<()>();
+
+        let mut first = run(&pool, "first", |_| async move {
+            receiver.await.expect("Finish first job");
+        });
+        let mut second = run(&pool, "second", |_| async {});
+
+        assert_eq!(poll(&mut first), Some(Some(Update::Queued)));
+        assert_eq!(poll(&mut first), Some(Some(Update::Started)));
+        assert_eq!(poll(&mut second), Some(Some(Update::Queued)));
+        assert_eq!(poll(&mut second), None);
+
+        sender.send(()).expect("Send to first job");
+
+        assert_eq!(poll(&mut first), Some(Some(Update::Finished(()))));
+        assert_eq!(poll(&mut first), Some(None));
+        assert_eq!(poll(&mut second), Some(Some(Update::Started)));
+    }
+
+    #[test]
+    fn releases_slot_on_cancellation() {
+        let pool = Pool::new(1);
+
+        let mut first = run(&pool, "first", |_| future::pending::<()>());
+        let mut second = run(&pool, "second", |_| future::pending::<()>());
+        let mut third = run(&pool, "third", |_| async {});
+
+        assert_eq!(poll(&mut first), Some(Some(Update::Queued)));
+        assert_eq!(poll(&mut first), Some(Some(Update::Started)));
+        assert_eq!(poll(&mut second), Some(Some(Update::Queued)));
+        assert_eq!(poll(&mut second), None);
+        assert_eq!(poll(&mut third), Some(Some(Update::Queued)));
+        assert_eq!(poll(&mut third), None);
+
+        // A cancelled job that is still queued gives up its turn
+        drop(second);
+        drop(first);
+
+        assert_eq!(poll(&mut third), Some(Some(Update::Started)));
+        assert_eq!(poll(&mut third), Some(Some(Update::Finished(()))));
+    }
+}
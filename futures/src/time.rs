@@ -11,6 +11,22 @@ pub fn every<H: std::hash::Hasher, E>(
     Subscription::from_recipe(Every(duration))
 }
 
+/// Returns a monotonic [`Subscription`] that produces the current time on
+/// every frame boundary.
+///
+/// Frame boundaries happen 60 times per second and are aligned to a single
+/// epoch shared by the whole process. Therefore, every [`clock`] ticks at the
+/// same instants, no matter when it was started.
+///
+/// All the [`clock`] subscriptions have the same identity. Prefer to map a
+/// single [`clock`] and share its messages between every part of your
+/// application that needs to react to time, instead of spawning a different
+/// timer for each one.
+pub fn clock<H: std::hash::Hasher, E>() -> Subscription<H, E, std::time::Instant>
+{
+    Subscription::from_recipe(Clock)
+}
+
 struct Every(std::time::Duration);
 
 #[cfg(feature = "async-std")]
@@ -72,3 +88,74 @@ where
             .boxed()
     }
 }
+
+struct Clock;
+
+impl Clock {
+    const FRAME: std::time::Duration =
+        std::time::Duration::from_nanos(1_000_000_000 / 60);
+
+    fn epoch() -> std::time::Instant {
+        static EPOCH: once_cell::sync::Lazy<std::time::Instant> =
+            once_cell::sync::Lazy::new(std::time::Instant::now);
+
+        *EPOCH
+    }
+
+    /// Returns the first frame boundary after the given instant.
+    fn next_frame(after: std::time::Instant) -> std::time::Instant {
+        let frame = Self::FRAME.as_nanos();
+        let elapsed = (after - Self::epoch()).as_nanos();
+        let frames = elapsed / frame + 1;
+
+        Self::epoch() + std::time::Duration::from_nanos((frames * frame) as u64)
+    }
+}
+
+impl<H, E> subscription::Recipe<H, E> for Clock
+where
+    H: std::hash::Hasher,
+{
+    type Output = std::time::Instant;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: futures::stream::BoxStream<'static, E>,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        use futures::stream::{self, StreamExt};
+        use std::time::Instant;
+
+        stream::unfold(Clock::next_frame(Instant::now()), |frame| async move {
+            let now = Instant::now();
+
+            if frame > now {
+                sleep(frame - now).await;
+            }
+
+            // Skip the frames we missed, instead of catching up with them
+            Some((Instant::now(), Clock::next_frame(frame.max(now))))
+        })
+        .boxed()
+    }
+}
+
+/// Waits for the given `duration` using the enabled executor.
+pub(crate) async fn sleep(duration: std::time::Duration) {
+    #[cfg(feature = "async-std")]
+    async_std::task::sleep(duration).await;
+
+    #[cfg(all(feature = "tokio_old", not(feature = "async-std")))]
+    tokio_old::time::delay_for(duration).await;
+
+    #[cfg(all(
+        feature = "tokio",
+        not(any(feature = "tokio_old", feature = "async-std"))
+    ))]
+    tokio::time::sleep(duration).await;
+}
@@ -36,6 +36,15 @@ impl Backend {
         }
     }
 
+    /// Applies the default font and text size of the given [`Settings`].
+    ///
+    /// The antialiasing strategy is ignored, as the amount of samples is
+    /// chosen when the OpenGL context is created.
+    pub(crate) fn configure(&mut self, settings: Settings) {
+        self.text_pipeline.set_default_font(settings.default_font);
+        self.default_text_size = settings.default_text_size;
+    }
+
     /// Draws the provided primitives in the default framebuffer.
     ///
     /// The text provided as overlay will be rendered on top of the primitives.
@@ -8,35 +8,16 @@ pub struct Pipeline {
     draw_brush: RefCell<glow_glyph::GlyphBrush>,
     draw_font_map: RefCell<HashMap<String, glow_glyph::FontId>>,
     measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    default_font: glow_glyph::FontId,
+    default_fonts: Vec<(Option<&'static [u8]>, glow_glyph::FontId)>,
 }
 
 impl Pipeline {
-    pub fn new(gl: &glow::Context, default_font: Option<&[u8]>) -> Self {
-        let default_font = default_font.map(|slice| slice.to_vec());
-
-        // TODO: Font customization
-        #[cfg(feature = "default_system_font")]
-        let default_font = {
-            default_font.or_else(|| {
-                font::Source::new()
-                    .load(&[font::Family::SansSerif, font::Family::Serif])
-                    .ok()
-            })
-        };
-
-        let default_font =
-            default_font.unwrap_or_else(|| font::FALLBACK.to_vec());
-
-        let font = ab_glyph::FontArc::try_from_vec(default_font)
-            .unwrap_or_else(|_| {
-                log::warn!(
-                    "System font failed to load. Falling back to \
-                    embedded font..."
-                );
-
-                ab_glyph::FontArc::try_from_slice(font::FALLBACK)
-                    .expect("Load fallback font")
-            });
+    pub fn new(
+        gl: &glow::Context,
+        default_font: Option<&'static [u8]>,
+    ) -> Self {
+        let font = load_default_font(default_font);
 
         let draw_brush =
             glow_glyph::GlyphBrushBuilder::using_font(font.clone())
@@ -51,9 +32,39 @@ impl Pipeline {
             draw_brush: RefCell::new(draw_brush),
             draw_font_map: RefCell::new(HashMap::new()),
             measure_brush: RefCell::new(measure_brush),
+            default_font: glow_glyph::FontId(0),
+            default_fonts: vec![(default_font, glow_glyph::FontId(0))],
         }
     }
 
+    /// Uses the given font as the default one.
+    ///
+    /// The brushes cannot release their GPU resources, so they are kept and
+    /// every default font used is added to them only once.
+    pub fn set_default_font(&mut self, default_font: Option<&'static [u8]>) {
+        let known = self.default_fonts.iter().find(|(font, _)| {
+            match (font, default_font) {
+                (Some(a), Some(b)) => std::ptr::eq(*a, b),
+                (None, None) => true,
+                _ => false,
+            }
+        });
+
+        self.default_font = match known {
+            Some((_, font_id)) => *font_id,
+            None => {
+                let font = load_default_font(default_font);
+
+                let _ = self.measure_brush.borrow_mut().add_font(font.clone());
+                let font_id = self.draw_brush.borrow_mut().add_font(font);
+
+                self.default_fonts.push((default_font, font_id));
+
+                font_id
+            }
+        };
+    }
+
     pub fn queue(&mut self, section: glow_glyph::Section<'_>) {
         self.draw_brush.borrow_mut().queue(section);
     }
@@ -131,7 +142,7 @@ impl Pipeline {
 
     pub fn find_font(&self, font: iced_native::Font) -> glow_glyph::FontId {
         match font {
-            iced_native::Font::Default => glow_glyph::FontId(0),
+            iced_native::Font::Default => self.default_font,
             iced_native::Font::External { name, bytes } => {
                 if let Some(font_id) = self.draw_font_map.borrow().get(name) {
                     return *font_id;
@@ -154,3 +165,29 @@ impl Pipeline {
         }
     }
 }
+
+fn load_default_font(default_font: Option<&[u8]>) -> ab_glyph::FontArc {
+    let default_font = default_font.map(|slice| slice.to_vec());
+
+    // TODO: Font customization
+    #[cfg(feature = "default_system_font")]
+    let default_font = {
+        default_font.or_else(|| {
+            font::Source::new()
+                .load(&[font::Family::SansSerif, font::Family::Serif])
+                .ok()
+        })
+    };
+
+    let default_font = default_font.unwrap_or_else(|| font::FALLBACK.to_vec());
+
+    ab_glyph::FontArc::try_from_vec(default_font).unwrap_or_else(|_| {
+        log::warn!(
+            "System font failed to load. Falling back to \
+                    embedded font..."
+        );
+
+        ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+            .expect("Load fallback font")
+    })
+}
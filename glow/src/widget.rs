@@ -9,25 +9,54 @@
 //! ```
 use crate::Renderer;
 
+pub mod aspect_ratio;
+pub mod badge;
+pub mod breadcrumbs;
 pub mod button;
 pub mod checkbox;
+pub mod chip;
 pub mod container;
+pub mod cursor_area;
+pub mod duration_input;
+pub mod lazy;
+pub mod number_input;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod responsive;
 pub mod rule;
 pub mod scrollable;
+pub mod search_input;
+pub mod shortcut_input;
+pub mod skeleton;
 pub mod slider;
+pub mod tab_bar;
 pub mod text_input;
 
+#[doc(no_inline)]
+pub use aspect_ratio::AspectRatio;
+#[doc(no_inline)]
+pub use badge::Badge;
+#[doc(no_inline)]
+pub use breadcrumbs::Breadcrumbs;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use chip::Chip;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use cursor_area::CursorArea;
+#[doc(no_inline)]
+pub use duration_input::DurationInput;
+#[doc(no_inline)]
+pub use lazy::Lazy;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -36,12 +65,22 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use responsive::Responsive;
+#[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use search_input::SearchInput;
+#[doc(no_inline)]
+pub use shortcut_input::ShortcutInput;
+#[doc(no_inline)]
+pub use skeleton::Skeleton;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use tab_bar::TabBar;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 
 #[cfg(feature = "canvas")]
@@ -0,0 +1,9 @@
+//! Constrain some content to an aspect ratio.
+use crate::Renderer;
+
+/// An element that keeps its content at a fixed aspect ratio.
+///
+/// This is an alias of an `iced_native` aspect ratio with an
+/// `iced_glow::Renderer`.
+pub type AspectRatio<'a, Message> =
+    iced_native::AspectRatio<'a, Message, Renderer>;
@@ -0,0 +1,10 @@
+//! Highlight counts, statuses and notifications with small badges.
+use crate::Renderer;
+
+pub use iced_graphics::badge::{Style, StyleSheet};
+
+/// A small label that highlights a count or a status, or a dot that notifies
+/// that something needs attention.
+///
+/// This is an alias of an `iced_native` badge with an `iced_glow::Renderer`.
+pub type Badge = iced_native::Badge<Renderer>;
@@ -0,0 +1,12 @@
+//! Display compact labels that can be selected or pressed.
+//!
+//! A [`Chip`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::chip::{Style, StyleSheet};
+pub use iced_native::chip::State;
+
+/// A compact label that can optionally be selected or pressed.
+///
+/// This is an alias of an `iced_native` chip with an `iced_glow::Renderer`.
+pub type Chip<'a, Message> = iced_native::Chip<'a, Message, Renderer>;
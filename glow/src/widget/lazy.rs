@@ -0,0 +1,14 @@
+//! Avoid rebuilding parts of your user interface that have not changed.
+//!
+//! A [`Lazy`] widget only runs its view logic again when its dependency
+//! changes.
+use crate::Renderer;
+
+/// A widget that only rebuilds its contents when its dependency changes.
+///
+/// This is an alias of an `iced_native` lazy widget with an
+/// `iced_glow::Renderer`.
+pub type Lazy<'a, Message> = iced_native::Lazy<'a, Message, Renderer>;
+
+/// The memoized contents of a [`Lazy`] widget.
+pub type Cache<Message> = iced_native::lazy::Cache<Message, Renderer>;
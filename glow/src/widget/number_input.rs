@@ -0,0 +1,14 @@
+//! Enter numbers within a range, typing them or stepping with spinners.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::number_input::{State, Style, StyleSheet};
+
+/// A field that can be filled with a number within a range, with spinners to
+/// increment and decrement it.
+///
+/// This is an alias of an `iced_native` number input with an
+/// `iced_glow::Renderer`.
+pub type NumberInput<'a, T, Message> =
+    iced_native::NumberInput<'a, T, Message, Renderer>;
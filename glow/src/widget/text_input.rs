@@ -4,7 +4,10 @@
 use crate::Renderer;
 
 pub use iced_graphics::text_input::{Style, StyleSheet};
-pub use iced_native::text_input::State;
+pub use iced_native::text_input::{
+    focus, move_cursor_to, move_cursor_to_end, move_cursor_to_front,
+    select_all, State,
+};
 
 /// A field that can be filled with text.
 ///
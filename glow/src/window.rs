@@ -2,3 +2,4 @@
 mod compositor;
 
 pub use compositor::Compositor;
+pub use iced_graphics::window::Configuration;
@@ -2,6 +2,7 @@ use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
 use core::ffi::c_void;
 use glow::HasContext;
+use iced_graphics::window::Configuration;
 use iced_graphics::{Antialiasing, Size};
 use iced_native::mouse;
 
@@ -9,6 +10,7 @@ use iced_native::mouse;
 #[allow(missing_debug_implementations)]
 pub struct Compositor {
     gl: glow::Context,
+    settings: Settings,
 }
 
 impl iced_graphics::window::GLCompositor for Compositor {
@@ -33,7 +35,7 @@ impl iced_graphics::window::GLCompositor for Compositor {
 
         let renderer = Renderer::new(Backend::new(&gl, settings));
 
-        Ok((Self { gl }, renderer))
+        Ok((Self { gl, settings }, renderer))
     }
 
     fn sample_count(settings: &Settings) -> u32 {
@@ -54,6 +56,25 @@ impl iced_graphics::window::GLCompositor for Compositor {
         }
     }
 
+    fn configure(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        configuration: Option<Configuration>,
+    ) {
+        let settings = match configuration {
+            Some(configuration) => Settings {
+                default_font: configuration.default_font,
+                default_text_size: configuration.default_text_size,
+                antialiasing: configuration.antialiasing,
+            },
+            None => self.settings,
+        };
+
+        // Rebuilding the backend would leave its programs and buffers
+        // behind, so it is reconfigured in place instead
+        renderer.backend_mut().configure(settings);
+    }
+
     fn draw<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
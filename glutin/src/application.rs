@@ -8,7 +8,8 @@ use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::futures;
 use iced_winit::futures::channel::mpsc;
-use iced_winit::{Cache, Clipboard, Debug, Proxy, Settings};
+use iced_winit::widget::Operation;
+use iced_winit::{Cache, Clipboard, Debug, Inspector, Proxy, Settings};
 
 use glutin::window::Window;
 use std::mem::ManuallyDrop;
@@ -23,6 +24,7 @@ where
     A: Application + 'static,
     E: Executor + 'static,
     C: window::GLCompositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: Inspector,
 {
     use futures::task;
     use futures::Future;
@@ -47,10 +49,13 @@ where
     };
 
     let subscription = application.subscription();
+    let (init_futures, init_operations) = init_command.into_parts();
 
-    runtime.spawn(init_command);
+    runtime.spawn(init_futures);
     runtime.track(subscription);
 
+    let position = settings.window.position;
+
     let context = {
         let builder = settings.window.into_builder(
             &application.title(),
@@ -79,6 +84,12 @@ where
         }
     };
 
+    if let Some((x, y)) = position {
+        context
+            .window()
+            .set_outer_position(glutin::dpi::LogicalPosition { x, y });
+    }
+
     #[allow(unsafe_code)]
     let (compositor, renderer) = unsafe {
         C::new(compositor_settings, |address| {
@@ -96,6 +107,7 @@ where
         runtime,
         debug,
         receiver,
+        init_operations,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -120,6 +132,9 @@ where
     });
 }
 
+// The instance takes ownership of everything the event loop sets up before
+// it starts running, including the operations of the initial command.
+#[allow(clippy::too_many_arguments)]
 async fn run_instance<A, E, C>(
     mut application: A,
     mut compositor: C,
@@ -128,10 +143,12 @@ async fn run_instance<A, E, C>(
     mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<glutin::event::Event<'_, A::Message>>,
+    init_operations: Vec<Box<dyn Operation<A::Message>>>,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
     C: window::GLCompositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: Inspector,
 {
     use glutin::event;
     use iced_winit::futures::stream::StreamExt;
@@ -140,6 +157,12 @@ async fn run_instance<A, E, C>(
 
     let mut state = application::State::new(&application, context.window());
     let mut viewport_version = state.viewport_version();
+    let mut configuration = application.configuration();
+
+    if configuration.is_some() {
+        compositor.configure(&mut renderer, configuration);
+    }
+
     let mut user_interface =
         ManuallyDrop::new(application::build_user_interface(
             &mut application,
@@ -149,8 +172,14 @@ async fn run_instance<A, E, C>(
             &mut debug,
         ));
 
+    application::run_operations(
+        &mut user_interface,
+        init_operations,
+        &mut runtime,
+    );
+
     let mut primitive =
-        user_interface.draw(&mut renderer, state.cursor_position());
+        debug.draw(&mut user_interface, &mut renderer, state.cursor_position());
     let mut mouse_interaction = mouse::Interaction::default();
 
     let mut events = Vec::new();
@@ -186,7 +215,7 @@ async fn run_instance<A, E, C>(
                         ManuallyDrop::into_inner(user_interface).into_cache();
 
                     // Update application
-                    application::update(
+                    let operations = application::update(
                         &mut application,
                         &mut runtime,
                         &mut debug,
@@ -196,6 +225,17 @@ async fn run_instance<A, E, C>(
                     // Update window
                     state.synchronize(&application, context.window());
 
+                    // Update renderer
+                    let new_configuration = application.configuration();
+
+                    if new_configuration != configuration {
+                        compositor.configure(&mut renderer, new_configuration);
+
+                        configuration = new_configuration;
+                    }
+
+                    let should_exit = application.should_exit();
+
                     user_interface =
                         ManuallyDrop::new(application::build_user_interface(
                             &mut application,
@@ -204,11 +244,24 @@ async fn run_instance<A, E, C>(
                             state.logical_size(),
                             &mut debug,
                         ));
+
+                    application::run_operations(
+                        &mut user_interface,
+                        operations,
+                        &mut runtime,
+                    );
+
+                    if should_exit {
+                        break;
+                    }
                 }
 
                 debug.draw_started();
-                primitive =
-                    user_interface.draw(&mut renderer, state.cursor_position());
+                primitive = debug.draw(
+                    &mut user_interface,
+                    &mut renderer,
+                    state.cursor_position(),
+                );
                 debug.draw_finished();
 
                 context.window().request_redraw();
@@ -232,8 +285,11 @@ async fn run_instance<A, E, C>(
                     debug.layout_finished();
 
                     debug.draw_started();
-                    primitive = user_interface
-                        .draw(&mut renderer, state.cursor_position());
+                    primitive = debug.draw(
+                        &mut user_interface,
+                        &mut renderer,
+                        state.cursor_position(),
+                    );
                     debug.draw_finished();
 
                     context.resize(glutin::dpi::PhysicalSize::new(
@@ -259,6 +315,9 @@ async fn run_instance<A, E, C>(
                 debug.render_finished();
 
                 if new_mouse_interaction != mouse_interaction {
+                    context.window().set_cursor_visible(
+                        new_mouse_interaction != mouse::Interaction::Hidden,
+                    );
                     context.window().set_cursor_icon(
                         conversion::mouse_interaction(new_mouse_interaction),
                     );
@@ -274,6 +333,7 @@ async fn run_instance<A, E, C>(
                 ..
             } => {
                 if application::requests_exit(&window_event, state.modifiers())
+                    && state.exit_on_close_request()
                 {
                     break;
                 }
@@ -7,18 +7,28 @@
 //! ```
 //! use iced_graphics::{button, Button};
 //! ```
+pub mod badge;
+pub mod breadcrumbs;
 pub mod button;
 pub mod checkbox;
+pub mod chip;
 pub mod container;
+pub mod cursor_area;
 pub mod image;
+pub mod image_viewer;
+pub mod number_input;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
 pub mod rule;
 pub mod scrollable;
+pub mod search_input;
+pub mod shortcut_input;
+pub mod skeleton;
 pub mod slider;
 pub mod svg;
+pub mod tab_bar;
 pub mod text_input;
 
 mod column;
@@ -26,13 +36,23 @@ mod row;
 mod space;
 mod text;
 
+#[doc(no_inline)]
+pub use badge::Badge;
+#[doc(no_inline)]
+pub use breadcrumbs::Breadcrumbs;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use chip::Chip;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use cursor_area::CursorArea;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -45,12 +65,21 @@ pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use search_input::SearchInput;
+#[doc(no_inline)]
+pub use shortcut_input::ShortcutInput;
+#[doc(no_inline)]
+pub use skeleton::Skeleton;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use tab_bar::TabBar;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 
 pub use column::Column;
 pub use image::Image;
+pub use image_viewer::ImageViewer;
 pub use row::Row;
 pub use space::Space;
 pub use svg::Svg;
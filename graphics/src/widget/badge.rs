@@ -0,0 +1,71 @@
+//! Highlight counts, statuses and notifications with small badges.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::badge;
+use iced_native::mouse;
+use iced_native::{HorizontalAlignment, Rectangle, VerticalAlignment};
+
+pub use iced_style::badge::{Style, StyleSheet};
+
+/// A small label that highlights a count or a status, or a dot that notifies
+/// that something needs attention.
+///
+/// This is an alias of an `iced_native` badge with an `iced_wgpu::Renderer`.
+pub type Badge<Backend> = iced_native::Badge<Renderer<Backend>>;
+
+impl<B> badge::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_TEXT_SIZE: u16 = 12;
+    const DEFAULT_DOT_SIZE: u16 = 8;
+    const DEFAULT_PADDING: u16 = 4;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        label: Option<&str>,
+        text_size: u16,
+        font: Self::Font,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style
+                .border_radius
+                .min(bounds.width.min(bounds.height) / 2.0),
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        (
+            match label {
+                Some(label) => Primitive::Group {
+                    primitives: vec![
+                        background,
+                        Primitive::Text {
+                            content: label.to_string(),
+                            size: f32::from(text_size),
+                            bounds: Rectangle {
+                                x: bounds.center_x(),
+                                y: bounds.center_y(),
+                                ..bounds
+                            },
+                            color: style.text_color,
+                            font,
+                            horizontal_alignment: HorizontalAlignment::Center,
+                            vertical_alignment: VerticalAlignment::Center,
+                        },
+                    ],
+                },
+                None => background,
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
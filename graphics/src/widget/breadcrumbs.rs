@@ -0,0 +1,118 @@
+//! Show and navigate the path to the current view.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::breadcrumbs;
+use iced_native::mouse;
+use iced_native::{
+    HorizontalAlignment, Layout, Point, Rectangle, VerticalAlignment,
+};
+
+pub use iced_style::breadcrumbs::{Style, StyleSheet};
+
+/// A path of segments where every segment but the current one can be
+/// selected.
+///
+/// This is an alias of an `iced_native` breadcrumbs with an
+/// `iced_wgpu::Renderer`.
+pub type Breadcrumbs<Message, Backend> =
+    iced_native::Breadcrumbs<Message, Renderer<Backend>>;
+
+impl<B> breadcrumbs::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SEPARATOR: &'static str = "/";
+    const DEFAULT_SPACING: u16 = 8;
+
+    fn draw(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        segments: &[String],
+        separator: &str,
+        text_size: u16,
+        font: Self::Font,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+        let bounds = layout.bounds();
+        let current = segments.len().saturating_sub(1);
+
+        let mut is_mouse_over_any = false;
+        let mut primitives = Vec::with_capacity(segments.len() * 2);
+        let mut previous: Option<Rectangle> = None;
+
+        let text = |content: &str, bounds: Rectangle, color, alignment| {
+            Primitive::Text {
+                content: content.to_string(),
+                size: f32::from(text_size),
+                bounds: Rectangle {
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                color,
+                font,
+                horizontal_alignment: alignment,
+                vertical_alignment: VerticalAlignment::Center,
+            }
+        };
+
+        for (index, (segment, layout)) in
+            segments.iter().zip(layout.children()).enumerate()
+        {
+            let segment_bounds = Rectangle {
+                y: bounds.y,
+                height: bounds.height,
+                ..layout.bounds()
+            };
+
+            if let Some(previous) = previous {
+                let start = previous.x + previous.width;
+                let gap = segment_bounds.x - start;
+
+                primitives.push(text(
+                    separator,
+                    Rectangle {
+                        x: start + gap / 2.0,
+                        width: gap,
+                        ..segment_bounds
+                    },
+                    style.separator_color,
+                    HorizontalAlignment::Center,
+                ));
+            }
+
+            let is_mouse_over =
+                index < current && segment_bounds.contains(cursor_position);
+
+            is_mouse_over_any = is_mouse_over_any || is_mouse_over;
+
+            let color = if index == current {
+                style.current_segment_color
+            } else if is_mouse_over {
+                style.hovered_segment_color
+            } else {
+                style.segment_color
+            };
+
+            primitives.push(text(
+                segment,
+                segment_bounds,
+                color,
+                HorizontalAlignment::Left,
+            ));
+            previous = Some(segment_bounds);
+        }
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over_any {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
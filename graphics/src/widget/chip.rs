@@ -0,0 +1,78 @@
+//! Display compact labels that can be selected or pressed.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::chip;
+use iced_native::mouse;
+use iced_native::{HorizontalAlignment, Point, Rectangle, VerticalAlignment};
+
+pub use iced_native::chip::State;
+pub use iced_style::chip::{Style, StyleSheet};
+
+/// A compact label that can optionally be selected or pressed.
+///
+/// This is an alias of an `iced_native` chip with an `iced_wgpu::Renderer`.
+pub type Chip<'a, Message, Backend> =
+    iced_native::Chip<'a, Message, Renderer<Backend>>;
+
+impl<B> chip::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 6;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        label: &str,
+        text_size: u16,
+        font: Self::Font,
+        is_selected: bool,
+        is_pressable: bool,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = is_pressable && bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style_sheet.hovered(is_selected)
+        } else {
+            style_sheet.active(is_selected)
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds,
+                        background: style.background,
+                        border_radius: style
+                            .border_radius
+                            .min(bounds.width.min(bounds.height) / 2.0),
+                        border_width: style.border_width,
+                        border_color: style.border_color,
+                    },
+                    Primitive::Text {
+                        content: label.to_string(),
+                        size: f32::from(text_size),
+                        bounds: Rectangle {
+                            x: bounds.center_x(),
+                            y: bounds.center_y(),
+                            ..bounds
+                        },
+                        color: style.text_color,
+                        font,
+                        horizontal_alignment: HorizontalAlignment::Center,
+                        vertical_alignment: VerticalAlignment::Center,
+                    },
+                ],
+            },
+            if is_mouse_over {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
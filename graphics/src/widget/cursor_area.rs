@@ -0,0 +1,48 @@
+//! Show a specific mouse cursor over some content.
+use crate::defaults::Defaults;
+use crate::{Backend, Renderer};
+use iced_native::cursor_area;
+use iced_native::mouse;
+use iced_native::{Element, Layout, Point, Rectangle};
+
+/// An element that shows a specific mouse cursor while it is hovered.
+///
+/// This is an alias of an `iced_native` cursor area with an
+/// `iced_wgpu::Renderer`.
+pub type CursorArea<'a, Message, Backend> =
+    iced_native::CursorArea<'a, Message, Renderer<Backend>>;
+
+impl<B> cursor_area::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw<Message>(
+        &mut self,
+        defaults: &Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        interaction: mouse::Interaction,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let (content, mouse_interaction) = content.draw(
+            self,
+            defaults,
+            content_layout,
+            cursor_position,
+            viewport,
+        );
+
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        (
+            content,
+            if mouse_interaction == mouse::Interaction::Idle && is_mouse_over {
+                interaction
+            } else {
+                mouse_interaction
+            },
+        )
+    }
+}
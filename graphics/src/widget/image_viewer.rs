@@ -0,0 +1,44 @@
+//! Zoom into images and pan around them.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::image;
+use iced_native::image_viewer;
+use iced_native::mouse;
+use iced_native::{Point, Rectangle, Vector};
+
+pub use iced_native::image_viewer::{ImageViewer, State};
+
+impl<B> image_viewer::Renderer for Renderer<B>
+where
+    B: Backend + backend::Image,
+{
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        image_bounds: Rectangle,
+        cursor_position: Point,
+        handle: image::Handle,
+        is_grabbed: bool,
+    ) -> Self::Output {
+        let is_pannable = image_bounds.width > bounds.width
+            || image_bounds.height > bounds.height;
+
+        (
+            Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Image {
+                    handle,
+                    bounds: image_bounds,
+                }),
+            },
+            if is_grabbed {
+                mouse::Interaction::Grabbing
+            } else if is_pannable && bounds.contains(cursor_position) {
+                mouse::Interaction::Grab
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
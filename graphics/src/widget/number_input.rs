@@ -0,0 +1,90 @@
+//! Enter numbers within a range, typing them or stepping with spinners.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::number_input;
+use iced_native::{
+    Color, HorizontalAlignment, Point, Rectangle, VerticalAlignment,
+};
+
+pub use iced_native::number_input::{Number, State};
+pub use iced_style::number_input::{Style, StyleSheet};
+
+/// A field that can be filled with a number within a range, with spinners to
+/// increment and decrement it.
+///
+/// This is an alias of an `iced_native` number input with an
+/// `iced_wgpu::Renderer`.
+pub type NumberInput<'a, T, Message, Backend> =
+    iced_native::NumberInput<'a, T, Message, Renderer<Backend>>;
+
+impl<B> number_input::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        increment_bounds: Rectangle,
+        decrement_bounds: Rectangle,
+        cursor_position: Point,
+        can_increment: bool,
+        can_decrement: bool,
+        (input, mouse_interaction): Self::Output,
+        style_sheet: &<Self as number_input::Renderer>::Style,
+    ) -> Self::Output {
+        let mut primitives = vec![input];
+        let mut is_mouse_over_any = false;
+
+        for (bounds, label, is_enabled) in &[
+            (decrement_bounds, "-", can_decrement),
+            (increment_bounds, "+", can_increment),
+        ] {
+            let is_mouse_over = *is_enabled && bounds.contains(cursor_position);
+
+            is_mouse_over_any = is_mouse_over_any || is_mouse_over;
+
+            let style = if !is_enabled {
+                style_sheet.disabled()
+            } else if is_mouse_over {
+                style_sheet.hovered()
+            } else {
+                style_sheet.active()
+            };
+
+            if let Some(background) = style.background {
+                primitives.push(Primitive::Quad {
+                    bounds: *bounds,
+                    background,
+                    border_radius: style.border_radius,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            primitives.push(Primitive::Text {
+                content: label.to_string(),
+                font: Default::default(),
+                size: bounds.height,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..*bounds
+                },
+                color: style.icon_color,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over_any {
+                mouse::Interaction::Pointer
+            } else {
+                mouse_interaction
+            },
+        )
+    }
+}
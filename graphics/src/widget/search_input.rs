@@ -0,0 +1,102 @@
+//! Filter large lists with a text field that debounces its searches.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::search_input;
+use iced_native::{
+    Background, Color, HorizontalAlignment, Point, Rectangle, VerticalAlignment,
+};
+
+pub use iced_native::search_input::State;
+pub use iced_style::search_input::{Style, StyleSheet};
+
+/// A text input with a search icon and a button to clear it.
+///
+/// This is an alias of an `iced_native` search input with an
+/// `iced_wgpu::Renderer`.
+pub type SearchInput<'a, Message, Backend> =
+    iced_native::SearchInput<'a, Message, Renderer<Backend>>;
+
+const CLEAR_ICON: char = '\u{00D7}';
+
+impl<B> search_input::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        icon_bounds: Rectangle,
+        clear_bounds: Option<Rectangle>,
+        cursor_position: Point,
+        (input, mouse_interaction): Self::Output,
+        style_sheet: &<Self as search_input::Renderer>::Style,
+    ) -> Self::Output {
+        let is_clear_hovered = clear_bounds
+            .map(|bounds| bounds.contains(cursor_position))
+            .unwrap_or(false);
+
+        let style = if is_clear_hovered {
+            style_sheet.clear_hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        // A magnifying glass: a ring with a handle at its bottom right
+        let lens_size = icon_bounds.width * 0.7;
+        let handle_size = (icon_bounds.width - lens_size).max(1.0);
+
+        let lens = Primitive::Quad {
+            bounds: Rectangle {
+                width: lens_size,
+                height: lens_size,
+                ..icon_bounds
+            },
+            background: Background::Color(Color::TRANSPARENT),
+            border_radius: lens_size / 2.0,
+            border_width: (icon_bounds.width / 10.0).max(1.0),
+            border_color: style.icon_color,
+        };
+
+        let handle = Primitive::Quad {
+            bounds: Rectangle {
+                x: icon_bounds.x + icon_bounds.width - handle_size,
+                y: icon_bounds.y + icon_bounds.height - handle_size,
+                width: handle_size,
+                height: handle_size,
+            },
+            background: Background::Color(style.icon_color),
+            border_radius: handle_size / 4.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        let mut primitives = vec![input, lens, handle];
+
+        if let Some(bounds) = clear_bounds {
+            primitives.push(Primitive::Text {
+                content: CLEAR_ICON.to_string(),
+                font: Default::default(),
+                size: bounds.height,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                color: style.clear_color,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            if is_clear_hovered {
+                mouse::Interaction::Pointer
+            } else {
+                mouse_interaction
+            },
+        )
+    }
+}
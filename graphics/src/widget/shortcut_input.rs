@@ -0,0 +1,84 @@
+//! Let users rebind keyboard shortcuts by pressing them.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::shortcut_input;
+use iced_native::{HorizontalAlignment, Point, Rectangle, VerticalAlignment};
+
+pub use iced_native::shortcut_input::State;
+pub use iced_style::shortcut_input::{Style, StyleSheet};
+
+/// A field that shows a keyboard shortcut and captures the next one pressed
+/// after clicking it.
+///
+/// This is an alias of an `iced_native` shortcut input with an
+/// `iced_wgpu::Renderer`.
+pub type ShortcutInput<'a, Message, Backend> =
+    iced_native::ShortcutInput<'a, Message, Renderer<Backend>>;
+
+impl<B> shortcut_input::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        label: &str,
+        is_assigned: bool,
+        is_capturing: bool,
+        text_size: u16,
+        font: Self::Font,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_capturing {
+            style_sheet.capturing()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds,
+                        background: style.background,
+                        border_radius: style.border_radius,
+                        border_width: style.border_width,
+                        border_color: style.border_color,
+                    },
+                    Primitive::Text {
+                        content: label.to_string(),
+                        size: f32::from(text_size),
+                        bounds: Rectangle {
+                            x: bounds.center_x(),
+                            y: bounds.center_y(),
+                            ..bounds
+                        },
+                        color: if is_assigned {
+                            style.text_color
+                        } else {
+                            style.placeholder_color
+                        },
+                        font,
+                        horizontal_alignment: HorizontalAlignment::Center,
+                        vertical_alignment: VerticalAlignment::Center,
+                    },
+                ],
+            },
+            if is_mouse_over {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
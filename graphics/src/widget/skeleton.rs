@@ -0,0 +1,112 @@
+//! Hold the place of content that is still loading.
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::skeleton;
+use iced_native::{Background, Color, Rectangle};
+
+pub use iced_style::skeleton::{Style, StyleSheet};
+
+/// A placeholder block shown while some content is loading.
+///
+/// This is an alias of an `iced_native` skeleton with an
+/// `iced_wgpu::Renderer`.
+pub type Skeleton<Backend> = iced_native::Skeleton<Renderer<Backend>>;
+
+/// The amount of quads used to fade the shimmer in and out.
+const SHIMMER_SLICES: usize = 12;
+
+impl<B> skeleton::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        shimmer: Option<f32>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let background = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.background),
+            border_radius: style.border_radius,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        let progress = match shimmer {
+            Some(progress) => progress,
+            None => return (background, mouse::Interaction::default()),
+        };
+
+        // The shimmer sweeps from fully outside on the left to fully outside
+        // on the right, fading in and out across its slices.
+        let band_width = (bounds.width / 2.0).max(1.0);
+        let slice_width = band_width / SHIMMER_SLICES as f32;
+        let start =
+            bounds.x - band_width + progress * (bounds.width + band_width);
+
+        let slices = (0..SHIMMER_SLICES).filter_map(|i| {
+            let left = (start + i as f32 * slice_width).max(bounds.x);
+            let right = (start + (i + 1) as f32 * slice_width)
+                .min(bounds.x + bounds.width);
+
+            if right <= left {
+                return None;
+            }
+
+            // Slices are kept inside the rounded corners of the background,
+            // which are the farthest from the center at either end.
+            let inset = corner_inset(bounds, style.border_radius, left)
+                .max(corner_inset(bounds, style.border_radius, right));
+
+            let center = (i as f32 + 0.5) / SHIMMER_SLICES as f32;
+            let intensity = 1.0 - (center * 2.0 - 1.0).abs();
+
+            Some(Primitive::Quad {
+                bounds: Rectangle {
+                    x: left,
+                    y: bounds.y + inset,
+                    width: right - left,
+                    height: bounds.height - inset * 2.0,
+                },
+                background: Background::Color(Color {
+                    a: style.shimmer_color.a * intensity,
+                    ..style.shimmer_color
+                }),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            })
+        });
+
+        (
+            Primitive::Group {
+                primitives: std::iter::once(background).chain(slices).collect(),
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// Returns the vertical distance between the edges of the given bounds and
+/// their rounded corners at the horizontal position `x`.
+fn corner_inset(bounds: Rectangle, border_radius: f32, x: f32) -> f32 {
+    let radius = border_radius
+        .min(bounds.width / 2.0)
+        .min(bounds.height / 2.0)
+        .max(0.0);
+
+    let distance = (x - bounds.x).min(bounds.x + bounds.width - x).max(0.0);
+
+    if distance >= radius {
+        return 0.0;
+    }
+
+    let offset = radius - distance;
+
+    radius - (radius * radius - offset * offset).sqrt()
+}
@@ -0,0 +1,111 @@
+//! Switch between views with a row of tabs.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::tab_bar;
+use iced_native::{
+    Background, HorizontalAlignment, Layout, Point, Rectangle,
+    VerticalAlignment,
+};
+
+pub use iced_style::tab_bar::{Style, StyleSheet};
+
+/// A row of tabs, one of which is selected.
+///
+/// This is an alias of an `iced_native` tab bar with an `iced_wgpu::Renderer`.
+pub type TabBar<Message, Backend> =
+    iced_native::TabBar<Message, Renderer<Backend>>;
+
+const INDICATOR_HEIGHT: f32 = 2.0;
+
+impl<B> tab_bar::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 10;
+
+    fn draw(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        labels: &[String],
+        selected: usize,
+        text_size: u16,
+        font: Self::Font,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let mut is_mouse_over_any = false;
+
+        let primitives = layout
+            .children()
+            .zip(labels)
+            .enumerate()
+            .flat_map(|(index, (tab, label))| {
+                let bounds = tab.bounds();
+                let is_selected = index == selected;
+                let is_mouse_over = bounds.contains(cursor_position);
+
+                is_mouse_over_any = is_mouse_over_any || is_mouse_over;
+
+                let style = if is_mouse_over {
+                    style_sheet.hovered(is_selected)
+                } else {
+                    style_sheet.active(is_selected)
+                };
+
+                let background =
+                    style.background.map(|background| Primitive::Quad {
+                        bounds,
+                        background,
+                        border_radius: style.border_radius,
+                        border_width: style.border_width,
+                        border_color: style.border_color,
+                    });
+
+                let label = Primitive::Text {
+                    content: label.clone(),
+                    size: f32::from(text_size),
+                    bounds: Rectangle {
+                        x: bounds.center_x(),
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    color: style.text_color,
+                    font,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                };
+
+                let indicator =
+                    style.indicator_color.map(|color| Primitive::Quad {
+                        bounds: Rectangle {
+                            y: bounds.y + bounds.height - INDICATOR_HEIGHT,
+                            height: INDICATOR_HEIGHT,
+                            ..bounds
+                        },
+                        background: Background::Color(color),
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: iced_native::Color::TRANSPARENT,
+                    });
+
+                background
+                    .into_iter()
+                    .chain(Some(label))
+                    .chain(indicator)
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over_any {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
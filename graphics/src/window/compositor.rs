@@ -1,3 +1,4 @@
+use crate::window::Configuration;
 use crate::{Color, Error, Viewport};
 use iced_native::mouse;
 use raw_window_handle::HasRawWindowHandle;
@@ -38,6 +39,22 @@ pub trait Compositor: Sized {
         height: u32,
     ) -> Self::SwapChain;
 
+    /// Applies the given [`Configuration`] to the [`Compositor`], rebuilding
+    /// the provided [`Renderer`] with it.
+    ///
+    /// If `None` is provided, the [`Configuration`] the compositor was
+    /// created with is applied again.
+    ///
+    /// By default, the [`Configuration`] is ignored.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn configure(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _configuration: Option<Configuration>,
+    ) {
+    }
+
     /// Draws the output primitives to the next frame of the given [`SwapChain`].
     ///
     /// [`SwapChain`]: Self::SwapChain
@@ -0,0 +1,47 @@
+use crate::Antialiasing;
+
+/// The settings of a renderer that can be changed while an application is
+/// running.
+#[derive(Debug, Clone, Copy)]
+pub struct Configuration {
+    /// The bytes of the font that will be used by default.
+    ///
+    /// If `None` is provided, a default system font will be chosen.
+    pub default_font: Option<&'static [u8]>,
+
+    /// The default size of text.
+    ///
+    /// By default, it will be set to 20.
+    pub default_text_size: u16,
+
+    /// The antialiasing strategy that will be used for triangle primitives.
+    pub antialiasing: Option<Antialiasing>,
+}
+
+impl PartialEq for Configuration {
+    fn eq(&self, other: &Self) -> bool {
+        // The runtime compares configurations after every update, so fonts
+        // are compared by address instead of byte by byte
+        let same_font = match (self.default_font, other.default_font) {
+            (Some(a), Some(b)) => std::ptr::eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+
+        same_font
+            && self.default_text_size == other.default_text_size
+            && self.antialiasing == other.antialiasing
+    }
+}
+
+impl Eq for Configuration {}
+
+impl Default for Configuration {
+    fn default() -> Configuration {
+        Configuration {
+            default_font: None,
+            default_text_size: 20,
+            antialiasing: None,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::window::Configuration;
 use crate::{Color, Error, Size, Viewport};
 use iced_native::mouse;
 
@@ -49,6 +50,25 @@ pub trait GLCompositor: Sized {
     /// Resizes the viewport of the [`GLCompositor`].
     fn resize_viewport(&mut self, physical_size: Size<u32>);
 
+    /// Applies the given [`Configuration`] to the [`GLCompositor`],
+    /// rebuilding the provided [`Renderer`] with it.
+    ///
+    /// The antialiasing strategy may be ignored, as the amount of samples
+    /// is chosen when the OpenGL context is created.
+    ///
+    /// If `None` is provided, the [`Configuration`] the compositor was
+    /// created with is applied again.
+    ///
+    /// By default, the [`Configuration`] is ignored.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn configure(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _configuration: Option<Configuration>,
+    ) {
+    }
+
     /// Draws the provided output with the given [`Renderer`].
     ///
     /// [`Renderer`]: crate::Renderer
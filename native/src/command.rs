@@ -0,0 +1,147 @@
+use crate::cancellation;
+use crate::futures::future::Future;
+use crate::widget::operation::{self, Operation};
+
+use std::fmt;
+use std::sync::Arc;
+
+/// A collection of async operations and operations on widgets.
+///
+/// You should be able to turn a future easily into a [`Command`], either by
+/// using the `From` trait or [`Command::perform`]. Use [`Command::widget`] to
+/// query or update the internal state of some widgets, like focusing a text
+/// input.
+pub struct Command<T> {
+    futures: iced_futures::Command<T>,
+    operations: Vec<Box<dyn Operation<T>>>,
+}
+
+impl<T> Command<T> {
+    /// Creates an empty [`Command`].
+    ///
+    /// In other words, a [`Command`] that does nothing.
+    pub fn none() -> Self {
+        Self {
+            futures: iced_futures::Command::none(),
+            operations: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the action of the given future.
+    pub fn perform<A>(
+        future: impl Future<Output = T> + 'static + Send,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Command {
+            futures: iced_futures::Command::perform(future, f),
+            operations: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that runs the given [`Operation`] on the widgets
+    /// of the user interface.
+    ///
+    /// The [`Operation`] runs once the user interface has been rebuilt after
+    /// the update that produced the [`Command`].
+    pub fn widget(operation: impl Operation<T> + 'static) -> Self {
+        Self {
+            futures: iced_futures::Command::none(),
+            operations: vec![Box::new(operation)],
+        }
+    }
+
+    /// Applies a transformation to the result of a [`Command`].
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + Send + Sync,
+    ) -> Command<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let f = Arc::new(f);
+        let futures = {
+            let f = f.clone();
+
+            self.futures.map(move |result| f(result))
+        };
+
+        Command {
+            futures,
+            operations: self
+                .operations
+                .into_iter()
+                .map(|operation| {
+                    let f = f.clone();
+
+                    Box::new(operation::map(operation, move |result| f(result)))
+                        as Box<dyn Operation<A>>
+                })
+                .collect(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the actions of all the given
+    /// commands.
+    ///
+    /// Once this command is run, all the commands will be executed at once.
+    pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
+        let mut futures = Vec::new();
+        let mut operations = Vec::new();
+
+        for command in commands {
+            futures.push(command.futures);
+            operations.extend(command.operations);
+        }
+
+        Self {
+            futures: iced_futures::Command::batch(futures),
+            operations,
+        }
+    }
+
+    /// Makes the [`Command`] cancellable with the given [`Token`].
+    ///
+    /// Once the [`Token`] is cancelled, the futures of the [`Command`] will be
+    /// dropped and no result will be produced. Its operations on widgets are
+    /// not affected, as they run right away.
+    ///
+    /// [`Token`]: cancellation::Token
+    pub fn cancellable(self, token: &cancellation::Token) -> Self
+    where
+        T: 'static + Send,
+    {
+        Self {
+            futures: self.futures.cancellable(token),
+            operations: self.operations,
+        }
+    }
+
+    /// Splits a [`Command`] into its futures and its operations on widgets.
+    pub fn into_parts(
+        self,
+    ) -> (iced_futures::Command<T>, Vec<Box<dyn Operation<T>>>) {
+        (self.futures, self.operations)
+    }
+}
+
+impl<T, A> From<A> for Command<T>
+where
+    T: 'static,
+    A: Future<Output = T> + 'static + Send,
+{
+    fn from(future: A) -> Self {
+        Self {
+            futures: iced_futures::Command::from(future),
+            operations: Vec::new(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Command<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Command")
+            .field("operations", &self.operations.len())
+            .finish()
+    }
+}
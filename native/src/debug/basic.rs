@@ -1,10 +1,25 @@
 #![allow(missing_docs)]
+use crate::layout;
+use crate::{Color, Point, Rectangle, UserInterface};
+
 use std::{collections::VecDeque, time};
 
+/// A renderer that the inspector of the [`struct@Debug`] view can explain
+/// the layout with.
+///
+/// Since the `debug` feature is enabled, it is implemented by any renderer
+/// that implements [`layout::Debugger`].
+pub trait Inspector: layout::Debugger {}
+
+impl<T> Inspector for T where T: layout::Debugger {}
+
 /// A bunch of time measurements for debugging purposes.
 #[derive(Debug)]
 pub struct Debug {
     is_enabled: bool,
+    is_inspecting: bool,
+    hovered: Vec<Rectangle>,
+    widgets: Vec<&'static str>,
 
     startup_start: time::Instant,
     startup_duration: time::Duration,
@@ -38,6 +53,9 @@ impl Debug {
 
         Self {
             is_enabled: false,
+            is_inspecting: false,
+            hovered: Vec::new(),
+            widgets: Vec::new(),
             startup_start: now,
             startup_duration: time::Duration::from_secs(0),
 
@@ -68,6 +86,52 @@ impl Debug {
         self.is_enabled = !self.is_enabled;
     }
 
+    pub fn toggle_inspector(&mut self) {
+        self.is_inspecting = !self.is_inspecting;
+    }
+
+    pub fn is_inspecting(&self) -> bool {
+        self.is_inspecting
+    }
+
+    /// Sets the bounds of the layout nodes and the type names of the widgets
+    /// under the cursor, from the root to the innermost one.
+    pub fn inspect(
+        &mut self,
+        hovered: Vec<Rectangle>,
+        widgets: Vec<&'static str>,
+    ) {
+        self.hovered = hovered;
+        self.widgets = widgets;
+    }
+
+    /// Draws a [`UserInterface`], explaining its layout and inspecting the
+    /// widgets under the cursor if the inspector is enabled.
+    pub fn draw<Message, Renderer>(
+        &mut self,
+        user_interface: &mut UserInterface<'_, Message, Renderer>,
+        renderer: &mut Renderer,
+        cursor_position: Point,
+    ) -> Renderer::Output
+    where
+        Renderer: Inspector,
+    {
+        if self.is_inspecting {
+            self.inspect(
+                user_interface.bounds_at(cursor_position),
+                user_interface.widgets_at(cursor_position),
+            );
+
+            user_interface.explain(
+                renderer,
+                cursor_position,
+                Color::from_rgb(1.0, 0.0, 1.0),
+            )
+        } else {
+            user_interface.draw(renderer, cursor_position)
+        }
+    }
+
     pub fn startup_started(&mut self) {
         self.startup_start = time::Instant::now();
     }
@@ -141,11 +205,15 @@ impl Debug {
     }
 
     pub fn overlay(&self) -> Vec<String> {
-        if !self.is_enabled {
-            return Vec::new();
+        let mut lines = Vec::new();
+
+        if self.is_inspecting {
+            lines.extend(self.inspector_overlay());
         }
 
-        let mut lines = Vec::new();
+        if !self.is_enabled {
+            return lines;
+        }
 
         fn key_value<T: std::fmt::Debug>(key: &str, value: T) -> String {
             format!("{} {:?}", key, value)
@@ -182,6 +250,41 @@ impl Debug {
 
         lines
     }
+
+    fn inspector_overlay(&self) -> Vec<String> {
+        let mut lines = vec![String::from("Inspector (Shift+F12)")];
+
+        match self.hovered.last() {
+            Some(bounds) => {
+                let widgets: Vec<&str> =
+                    self.widgets.iter().map(|name| short_name(name)).collect();
+
+                lines.push(format!(
+                    "Hovered: {} {}x{} at ({}, {})",
+                    widgets.last().unwrap_or(&"?"),
+                    bounds.width,
+                    bounds.height,
+                    bounds.x,
+                    bounds.y
+                ));
+                lines.push(format!("Depth: {}", self.hovered.len() - 1));
+                lines.push(format!("Path: {}", widgets.join(" > ")));
+            }
+            None => {
+                lines.push(String::from("Hovered: nothing"));
+            }
+        }
+
+        lines.push(String::new());
+        lines
+    }
+}
+
+/// Returns the name of a type without its path and generic parameters.
+fn short_name(type_name: &str) -> &str {
+    let path = type_name.split('<').next().unwrap_or(type_name);
+
+    path.rsplit("::").next().unwrap_or(path)
 }
 
 #[derive(Debug)]
@@ -216,3 +319,20 @@ impl TimeBuffer {
         sum / self.size.max(1) as u32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_name_strips_path_and_generics() {
+        assert_eq!(
+            short_name(
+                "iced_native::widget::button::Button<'_, app::Message, \
+                iced_graphics::renderer::Renderer<iced_wgpu::Backend>>"
+            ),
+            "Button"
+        );
+        assert_eq!(short_name("Layered"), "Layered");
+    }
+}
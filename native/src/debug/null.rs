@@ -1,4 +1,15 @@
 #![allow(missing_docs)]
+use crate::{Point, Rectangle, UserInterface};
+
+/// A renderer that the inspector of the [`struct@Debug`] view can explain
+/// the layout with.
+///
+/// Since the `debug` feature is disabled, there is no inspector and it is
+/// implemented by any renderer.
+pub trait Inspector: crate::Renderer {}
+
+impl<T> Inspector for T where T: crate::Renderer {}
+
 #[derive(Debug)]
 pub struct Debug;
 
@@ -7,6 +18,31 @@ impl Debug {
         Self
     }
 
+    pub fn toggle_inspector(&mut self) {}
+
+    pub fn is_inspecting(&self) -> bool {
+        false
+    }
+
+    pub fn inspect(
+        &mut self,
+        _hovered: Vec<Rectangle>,
+        _widgets: Vec<&'static str>,
+    ) {
+    }
+
+    pub fn draw<Message, Renderer>(
+        &mut self,
+        user_interface: &mut UserInterface<'_, Message, Renderer>,
+        renderer: &mut Renderer,
+        cursor_position: Point,
+    ) -> Renderer::Output
+    where
+        Renderer: Inspector,
+    {
+        user_interface.draw(renderer, cursor_position)
+    }
+
     pub fn startup_started(&mut self) {}
 
     pub fn startup_finished(&mut self) {}
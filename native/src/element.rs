@@ -1,6 +1,7 @@
 use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
+use crate::widget::operation::{self, Operation};
 use crate::{
     Clipboard, Color, Hasher, Layout, Length, Point, Rectangle, Widget,
 };
@@ -214,7 +215,10 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        self.widget.layout(renderer, limits)
+        let mut node = self.widget.layout(renderer, limits);
+        node.widget = Some(self.widget.type_name());
+
+        node
     }
 
     /// Processes a runtime [`Event`].
@@ -262,6 +266,15 @@ where
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
         self.widget.overlay(layout)
     }
+
+    /// Applies an [`Operation`] to the [`Element`] and its children.
+    pub fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.widget.operate(layout, operation);
+    }
 }
 
 struct Map<'a, A, B, Renderer> {
@@ -359,6 +372,19 @@ where
             .overlay(layout)
             .map(move |overlay| overlay.map(mapper))
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<B>,
+    ) {
+        self.widget
+            .operate(layout, &mut operation::Inner { operation });
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.widget.type_name()
+    }
 }
 
 struct Explain<'a, Message, Renderer: crate::Renderer> {
@@ -443,4 +469,16 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.element.overlay(layout)
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.element.operate(layout, operation);
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.element.widget.type_name()
+    }
 }
@@ -20,6 +20,14 @@ pub enum Event {
 }
 
 /// The status of an [`Event`] after being processed.
+///
+/// A widget that captures an [`Event`] stops its propagation: widgets that
+/// contain other widgets should not handle an [`Event`] that one of their
+/// children captured, like a [`Scrollable`] ignores the mouse wheel while its
+/// content is using it. Likewise, an [`Event`] captured by an overlay does
+/// not reach the widgets below it.
+///
+/// [`Scrollable`]: crate::widget::Scrollable
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
     /// The [`Event`] was **NOT** handled by any widget.
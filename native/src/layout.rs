@@ -37,6 +37,10 @@ impl<'a> Layout<'a> {
         self.position
     }
 
+    pub(crate) fn node(&self) -> &'a Node {
+        self.node
+    }
+
     /// Returns the bounds of the [`Layout`].
     ///
     /// The returned [`Rectangle`] describes the position and size of a
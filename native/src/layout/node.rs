@@ -5,6 +5,7 @@ use crate::{Align, Point, Rectangle, Size};
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    pub(crate) widget: Option<&'static str>,
 }
 
 impl Node {
@@ -23,6 +24,7 @@ impl Node {
                 height: size.height,
             },
             children,
+            widget: None,
         }
     }
 
@@ -41,6 +43,14 @@ impl Node {
         &self.children
     }
 
+    /// Returns the type name of the widget that produced the [`Node`], if it
+    /// was laid out as an [`Element`].
+    ///
+    /// [`Element`]: crate::Element
+    pub fn widget(&self) -> Option<&'static str> {
+        self.widget
+    }
+
     /// Aligns the [`Node`] in the given space.
     pub fn align(
         &mut self,
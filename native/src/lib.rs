@@ -45,6 +45,7 @@ pub mod widget;
 pub mod window;
 
 mod clipboard;
+mod command;
 mod element;
 mod hasher;
 mod runtime;
@@ -63,13 +64,14 @@ pub use iced_core::{
     Align, Background, Color, Font, HorizontalAlignment, Length, Point,
     Rectangle, Size, Vector, VerticalAlignment,
 };
-pub use iced_futures::{executor, futures, Command};
+pub use iced_futures::{cancellation, executor, futures};
 
 #[doc(no_inline)]
 pub use executor::Executor;
 
 pub use clipboard::Clipboard;
-pub use debug::Debug;
+pub use command::Command;
+pub use debug::{Debug, Inspector};
 pub use element::Element;
 pub use event::Event;
 pub use hasher::Hasher;
@@ -1,8 +1,9 @@
 use crate::{
-    button, checkbox, column, container, pane_grid, progress_bar, radio, row,
-    scrollable, slider, text, text_input, Color, Element, Font,
-    HorizontalAlignment, Layout, Point, Rectangle, Renderer, Size,
-    VerticalAlignment,
+    badge, breadcrumbs, button, checkbox, chip, column, container, cursor_area,
+    mouse, number_input, pane_grid, progress_bar, radio, row, scrollable,
+    search_input, shortcut_input, skeleton, slider, tab_bar, text, text_input,
+    Color, Element, Font, HorizontalAlignment, Layout, Point, Rectangle,
+    Renderer, Size, VerticalAlignment,
 };
 
 /// A renderer that does nothing.
@@ -197,6 +198,141 @@ impl checkbox::Renderer for Null {
     }
 }
 
+impl badge::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_TEXT_SIZE: u16 = 12;
+    const DEFAULT_DOT_SIZE: u16 = 8;
+    const DEFAULT_PADDING: u16 = 4;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _label: Option<&str>,
+        _text_size: u16,
+        _font: Font,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl chip::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 6;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _label: &str,
+        _text_size: u16,
+        _font: Font,
+        _is_selected: bool,
+        _is_pressable: bool,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl tab_bar::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 10;
+
+    fn draw(
+        &mut self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _labels: &[String],
+        _selected: usize,
+        _text_size: u16,
+        _font: Font,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl breadcrumbs::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_SEPARATOR: &'static str = "/";
+    const DEFAULT_SPACING: u16 = 8;
+
+    fn draw(
+        &mut self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _segments: &[String],
+        _separator: &str,
+        _text_size: u16,
+        _font: Font,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl search_input::Renderer for Null {
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _icon_bounds: Rectangle,
+        _clear_bounds: Option<Rectangle>,
+        _cursor_position: Point,
+        _input: Self::Output,
+        _style: &<Self as search_input::Renderer>::Style,
+    ) {
+    }
+}
+
+impl number_input::Renderer for Null {
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _increment_bounds: Rectangle,
+        _decrement_bounds: Rectangle,
+        _cursor_position: Point,
+        _can_increment: bool,
+        _can_decrement: bool,
+        _input: Self::Output,
+        _style: &<Self as number_input::Renderer>::Style,
+    ) {
+    }
+}
+
+impl shortcut_input::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _label: &str,
+        _is_assigned: bool,
+        _is_capturing: bool,
+        _text_size: u16,
+        _font: Font,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl skeleton::Renderer for Null {
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _shimmer: Option<f32>,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
 impl slider::Renderer for Null {
     type Style = ();
 
@@ -245,6 +381,20 @@ impl container::Renderer for Null {
     }
 }
 
+impl cursor_area::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+        _interaction: mouse::Interaction,
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) {
+    }
+}
+
 impl pane_grid::Renderer for Null {
     fn draw<Message>(
         &mut self,
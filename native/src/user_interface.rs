@@ -1,7 +1,10 @@
 use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
-use crate::{Clipboard, Element, Layout, Point, Rectangle, Size};
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Color, Element, Layout, Point, Rectangle, Size, Widget,
+};
 
 use std::hash::Hasher;
 
@@ -129,6 +132,10 @@ where
     /// It returns __messages__ that may have been produced as a result of user
     /// interactions. You should feed these to your __update logic__.
     ///
+    /// Events reach the active overlay, if any, before the rest of the
+    /// widgets. An event captured by the overlay stops there, so the widgets
+    /// below it never see it.
+    ///
     /// # Example
     /// Let's allow our [counter](index.html#usage) to change state by
     /// completing [the previous example](#example):
@@ -242,6 +249,10 @@ where
             .cloned()
             .zip(overlay_statuses.into_iter())
             .map(|(event, overlay_status)| {
+                if let event::Status::Captured = overlay_status {
+                    return event::Status::Captured;
+                }
+
                 let event_status = self.root.widget.on_event(
                     event,
                     Layout::new(&self.base.layout),
@@ -251,7 +262,7 @@ where
                     clipboard,
                 );
 
-                event_status.merge(overlay_status)
+                event_status
             })
             .collect()
     }
@@ -331,6 +342,107 @@ where
         &mut self,
         renderer: &mut Renderer,
         cursor_position: Point,
+    ) -> Renderer::Output {
+        self.draw_with(
+            renderer,
+            cursor_position,
+            |widget, renderer, layout, cursor_position, viewport| {
+                widget.draw(
+                    renderer,
+                    &Renderer::Defaults::default(),
+                    layout,
+                    cursor_position,
+                    viewport,
+                )
+            },
+        )
+    }
+
+    /// Draws the [`UserInterface`] like [`draw`] does, explaining the layout
+    /// of its widgets graphically with the given [`Color`].
+    ///
+    /// Overlays are drawn without explaining them.
+    ///
+    /// [`draw`]: Self::draw
+    pub fn explain(
+        &mut self,
+        renderer: &mut Renderer,
+        cursor_position: Point,
+        color: Color,
+    ) -> Renderer::Output
+    where
+        Renderer: layout::Debugger,
+    {
+        self.draw_with(
+            renderer,
+            cursor_position,
+            |widget, renderer, layout, cursor_position, viewport| {
+                renderer.explain(
+                    &Renderer::Defaults::default(),
+                    widget,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    color,
+                )
+            },
+        )
+    }
+
+    /// Returns the bounds of the widgets under the given position, from the
+    /// root of the [`UserInterface`] to the innermost widget.
+    ///
+    /// Overlays are not taken into account.
+    pub fn bounds_at(&self, position: Point) -> Vec<Rectangle> {
+        self.layouts_at(position)
+            .iter()
+            .map(Layout::bounds)
+            .collect()
+    }
+
+    /// Returns the type names of the widgets under the given position, from
+    /// the root of the [`UserInterface`] to the innermost widget.
+    ///
+    /// Overlays are not taken into account.
+    pub fn widgets_at(&self, position: Point) -> Vec<&'static str> {
+        self.layouts_at(position)
+            .iter()
+            .filter_map(|layout| layout.node().widget())
+            .collect()
+    }
+
+    fn layouts_at(&self, position: Point) -> Vec<Layout<'_>> {
+        let mut layouts = Vec::new();
+        let mut layout = Some(Layout::new(&self.base.layout));
+
+        while let Some(current) = layout {
+            if !current.bounds().contains(position) {
+                break;
+            }
+
+            layouts.push(current);
+
+            // Later children are drawn on top of earlier ones
+            layout = current
+                .children()
+                .filter(|child| child.bounds().contains(position))
+                .last();
+        }
+
+        layouts
+    }
+
+    fn draw_with(
+        &mut self,
+        renderer: &mut Renderer,
+        cursor_position: Point,
+        draw_base: impl FnOnce(
+            &dyn Widget<Message, Renderer>,
+            &mut Renderer,
+            Layout<'_>,
+            Point,
+            &Rectangle,
+        ) -> Renderer::Output,
     ) -> Renderer::Output {
         let viewport = Rectangle::with_size(self.bounds);
 
@@ -367,9 +479,9 @@ where
                 cursor_position
             };
 
-            let base_primitives = self.root.widget.draw(
+            let base_primitives = draw_base(
+                self.root.widget.as_ref(),
                 renderer,
-                &Renderer::Defaults::default(),
                 Layout::new(&self.base.layout),
                 base_cursor,
                 &viewport,
@@ -381,9 +493,9 @@ where
                 overlay_bounds,
             )
         } else {
-            self.root.widget.draw(
+            draw_base(
+                self.root.widget.as_ref(),
                 renderer,
-                &Renderer::Defaults::default(),
                 Layout::new(&self.base.layout),
                 cursor_position,
                 &viewport,
@@ -391,6 +503,15 @@ where
         }
     }
 
+    /// Applies an [`Operation`] to the widgets of the [`UserInterface`].
+    ///
+    /// Overlays are not traversed.
+    pub fn operate(&mut self, operation: &mut dyn Operation<Message>) {
+        self.root
+            .widget
+            .operate(Layout::new(&self.base.layout), operation);
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
@@ -477,3 +598,192 @@ impl Default for Cache {
         Cache::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::overlay::Overlay;
+    use crate::renderer::Null;
+    use crate::{mouse, Hasher, Length};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Overlay,
+        Base,
+    }
+
+    /// A widget that fills its bounds and displays a [`Popup`] in its
+    /// top-left corner. Both report every event they receive.
+    struct Layered {
+        popup_status: event::Status,
+    }
+
+    struct Popup {
+        status: event::Status,
+    }
+
+    impl Widget<Message, Null> for Layered {
+        fn width(&self) -> Length {
+            Length::Fill
+        }
+
+        fn height(&self) -> Length {
+            Length::Fill
+        }
+
+        fn layout(
+            &self,
+            _renderer: &Null,
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(limits.fill())
+        }
+
+        fn draw(
+            &self,
+            _renderer: &mut Null,
+            _defaults: &(),
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _viewport: &Rectangle,
+        ) {
+        }
+
+        fn hash_layout(&self, _state: &mut Hasher) {}
+
+        fn on_event(
+            &mut self,
+            _event: Event,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            messages: &mut Vec<Message>,
+            _renderer: &Null,
+            _clipboard: Option<&dyn Clipboard>,
+        ) -> event::Status {
+            messages.push(Message::Base);
+
+            event::Status::Ignored
+        }
+
+        fn overlay(
+            &mut self,
+            layout: Layout<'_>,
+        ) -> Option<overlay::Element<'_, Message, Null>> {
+            Some(overlay::Element::new(
+                layout.position(),
+                Box::new(Popup {
+                    status: self.popup_status,
+                }),
+            ))
+        }
+    }
+
+    impl Overlay<Message, Null> for Popup {
+        fn layout(
+            &self,
+            _renderer: &Null,
+            _bounds: Size,
+            position: Point,
+        ) -> layout::Node {
+            let mut node = layout::Node::new(Size::new(50.0, 50.0));
+            node.move_to(position);
+
+            node
+        }
+
+        fn draw(
+            &self,
+            _renderer: &mut Null,
+            _defaults: &(),
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+        ) {
+        }
+
+        fn hash_layout(&self, _state: &mut Hasher, _position: Point) {}
+
+        fn on_event(
+            &mut self,
+            _event: Event,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            messages: &mut Vec<Message>,
+            _renderer: &Null,
+            _clipboard: Option<&dyn Clipboard>,
+        ) -> event::Status {
+            messages.push(Message::Overlay);
+
+            self.status
+        }
+    }
+
+    fn click(
+        popup_status: event::Status,
+    ) -> (Vec<event::Status>, Vec<Message>) {
+        let mut renderer = Null::new();
+        let mut messages = Vec::new();
+
+        let mut user_interface = UserInterface::build(
+            Element::new(Layered { popup_status }),
+            Size::new(200.0, 200.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        let statuses = user_interface.update(
+            &[Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left,
+            ))],
+            Point::new(10.0, 10.0),
+            None,
+            &renderer,
+            &mut messages,
+        );
+
+        (statuses, messages)
+    }
+
+    #[test]
+    fn widgets_at_names_widgets_under_position() {
+        let mut renderer = Null::new();
+
+        let user_interface = UserInterface::build(
+            crate::Column::new().push(
+                Element::new(Layered {
+                    popup_status: event::Status::Ignored,
+                })
+                .map(|message| message),
+            ),
+            Size::new(200.0, 200.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        let widgets = user_interface.widgets_at(Point::new(10.0, 10.0));
+
+        assert_eq!(widgets.len(), 2);
+        assert!(widgets[0].starts_with("iced_native::widget::column::Column<"));
+        assert_eq!(widgets[1], std::any::type_name::<Layered>());
+
+        assert!(user_interface
+            .widgets_at(Point::new(-10.0, 10.0))
+            .is_empty());
+    }
+
+    #[test]
+    fn events_captured_by_overlay_stop_there() {
+        let (statuses, messages) = click(event::Status::Captured);
+
+        assert_eq!(statuses, vec![event::Status::Captured]);
+        assert_eq!(messages, vec![Message::Overlay]);
+    }
+
+    #[test]
+    fn events_ignored_by_overlay_reach_base() {
+        let (statuses, messages) = click(event::Status::Ignored);
+
+        assert_eq!(statuses, vec![event::Status::Ignored]);
+        assert_eq!(messages, vec![Message::Overlay, Message::Base]);
+    }
+}
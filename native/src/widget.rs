@@ -19,35 +19,69 @@
 //! ```
 //!
 //! [renderer]: crate::renderer
+pub mod aspect_ratio;
+pub mod badge;
+pub mod breadcrumbs;
 pub mod button;
 pub mod checkbox;
+pub mod chip;
 pub mod column;
 pub mod container;
+pub mod cursor_area;
+pub mod duration_input;
 pub mod image;
+pub mod image_viewer;
+pub mod lazy;
+pub mod number_input;
+pub mod operation;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod responsive;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
+pub mod search_input;
+pub mod shortcut_input;
+pub mod skeleton;
 pub mod slider;
 pub mod space;
 pub mod svg;
+pub mod tab_bar;
 pub mod text;
 pub mod text_input;
+pub mod viewport;
 
+#[doc(no_inline)]
+pub use aspect_ratio::AspectRatio;
+#[doc(no_inline)]
+pub use badge::Badge;
+#[doc(no_inline)]
+pub use breadcrumbs::Breadcrumbs;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use chip::Chip;
+#[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use cursor_area::CursorArea;
+#[doc(no_inline)]
+pub use duration_input::DurationInput;
+#[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use image_viewer::ImageViewer;
+#[doc(no_inline)]
+pub use lazy::Lazy;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -56,22 +90,37 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use responsive::Responsive;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use search_input::SearchInput;
+#[doc(no_inline)]
+pub use shortcut_input::ShortcutInput;
+#[doc(no_inline)]
+pub use skeleton::Skeleton;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
 pub use svg::Svg;
 #[doc(no_inline)]
+pub use tab_bar::TabBar;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
 
+mod id;
+
+pub use id::Id;
+pub use operation::Operation;
+
 use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
@@ -175,4 +224,25 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         None
     }
+
+    /// Applies an [`Operation`] to the [`Widget`].
+    ///
+    /// Widgets with some internal state should expose it to the
+    /// [`Operation`], and widgets with children should let it traverse them.
+    ///
+    /// By default, it does nothing.
+    fn operate(
+        &mut self,
+        _layout: Layout<'_>,
+        _operation: &mut dyn Operation<Message>,
+    ) {
+    }
+
+    /// Returns the name of the type of the [`Widget`].
+    ///
+    /// The inspector of the debug view shows it for the widgets under the
+    /// cursor. Widgets that wrap another one should return its name instead.
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
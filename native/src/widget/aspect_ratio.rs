@@ -0,0 +1,222 @@
+//! Constrain some content to an aspect ratio.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::overlay;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// An element that keeps its content at a fixed aspect ratio.
+///
+/// An [`AspectRatio`] takes the largest size with its ratio that fits the
+/// available space, and it lays out its content to fill that size exactly.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, AspectRatio};
+/// #
+/// # pub type Text = iced_native::Text<Null>;
+/// let thumbnail: AspectRatio<'_, (), Null> =
+///     AspectRatio::new(16.0 / 9.0, Text::new("Loading...")).max_width(320);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct AspectRatio<'a, Message, Renderer> {
+    ratio: f32,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> AspectRatio<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`AspectRatio`] with the given ratio of width to height
+    /// and content.
+    ///
+    /// For instance, a ratio of `16.0 / 9.0` produces widescreen content.
+    ///
+    /// It will panic if the ratio is not a positive, finite number.
+    pub fn new<T>(ratio: f32, content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        assert!(
+            ratio > 0.0 && ratio.is_finite(),
+            "Aspect ratio must be positive and finite"
+        );
+
+        AspectRatio {
+            ratio,
+            width: Length::Fill,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the width of the [`AspectRatio`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`AspectRatio`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`AspectRatio`].
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`AspectRatio`].
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for AspectRatio<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .loose()
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        let available = limits.max();
+
+        let mut width = available.width.min(available.height * self.ratio);
+
+        if !width.is_finite() {
+            // Nothing bounds the ratio, so we let the content choose
+            width = self.content.layout(renderer, &limits).size().width;
+        }
+
+        let size = Size::new(width, width / self.ratio);
+        let content = self
+            .content
+            .layout(renderer, &layout::Limits::new(size, size));
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.content.draw(
+            renderer,
+            defaults,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.ratio.to_bits().hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .operate(layout.children().next().unwrap(), operation);
+    }
+}
+
+impl<'a, Message, Renderer> From<AspectRatio<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        aspect_ratio: AspectRatio<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(aspect_ratio)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::Text;
+
+    #[test]
+    #[should_panic(expected = "Aspect ratio must be positive and finite")]
+    fn rejects_invalid_ratios() {
+        let _: AspectRatio<'_, (), Null> =
+            AspectRatio::new(0.0, Text::new("Loading..."));
+    }
+}
@@ -0,0 +1,201 @@
+//! Highlight counts, statuses and notifications with small badges.
+use crate::layout;
+use crate::text;
+use crate::{Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget};
+
+use std::hash::Hash;
+
+/// A small label that highlights a count or a status, or a dot that notifies
+/// that something needs attention.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Badge = iced_native::Badge<Null>;
+/// let unread = 3;
+///
+/// let count = Badge::new(unread.to_string());
+/// let live = Badge::new("LIVE");
+/// let notification = Badge::dot();
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Badge<Renderer: self::Renderer> {
+    label: Option<String>,
+    size: Option<u16>,
+    padding: u16,
+    font: Renderer::Font,
+    style: Renderer::Style,
+}
+
+impl<Renderer: self::Renderer> Badge<Renderer> {
+    /// Creates a new [`Badge`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Badge {
+            label: Some(label.into()),
+            size: None,
+            padding: Renderer::DEFAULT_PADDING,
+            font: Default::default(),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Creates a new [`Badge`] without a label, displayed as a dot.
+    pub fn dot() -> Self {
+        Badge {
+            label: None,
+            ..Self::new("")
+        }
+    }
+
+    /// Sets the size of the [`Badge`].
+    ///
+    /// It is the text size of a [`Badge`] with a label, or the diameter of a
+    /// dot.
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the padding of the [`Badge`].
+    ///
+    /// It has no effect on a dot.
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the [`Font`] of the label of the [`Badge`].
+    ///
+    /// [`Font`]: crate::widget::text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Badge`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Badge<Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = match &self.label {
+            Some(label) => {
+                let padding = f32::from(self.padding);
+
+                let (width, height) = renderer.measure(
+                    label,
+                    self.size.unwrap_or(Renderer::DEFAULT_TEXT_SIZE),
+                    self.font,
+                    Size::INFINITY,
+                );
+
+                let height = height + padding;
+
+                // Badges with short labels stay round
+                Size::new((width + padding * 2.0).max(height), height)
+            }
+            None => {
+                let diameter =
+                    f32::from(self.size.unwrap_or(Renderer::DEFAULT_DOT_SIZE));
+
+                Size::new(diameter, diameter)
+            }
+        };
+
+        layout::Node::new(limits.resolve(size))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            self.label.as_deref(),
+            self.size.unwrap_or(Renderer::DEFAULT_TEXT_SIZE),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.label.hash(state);
+        self.size.hash(state);
+        self.padding.hash(state);
+    }
+}
+
+/// The renderer of a [`Badge`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Badge`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default text size of a [`Badge`] with a label.
+    const DEFAULT_TEXT_SIZE: u16;
+
+    /// The default diameter of a dot [`Badge`].
+    const DEFAULT_DOT_SIZE: u16;
+
+    /// The default padding of a [`Badge`] with a label.
+    const DEFAULT_PADDING: u16;
+
+    /// Draws a [`Badge`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Badge`]
+    ///   * the label of the [`Badge`], if it is not a dot
+    ///   * the text size of the label
+    ///   * the font of the label
+    ///   * the style of the [`Badge`]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        label: Option<&str>,
+        text_size: u16,
+        font: Self::Font,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Badge<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(badge: Badge<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(badge)
+    }
+}
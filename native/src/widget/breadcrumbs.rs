@@ -0,0 +1,269 @@
+//! Show and navigate the path to the current view.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::text;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A path of segments separated by a separator, where every segment but the
+/// last one, the current one, can be selected.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Breadcrumbs<Message> = iced_native::Breadcrumbs<Message, Null>;
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     SegmentSelected(usize),
+/// }
+///
+/// let breadcrumbs = Breadcrumbs::new(
+///     vec![
+///         String::from("Library"),
+///         String::from("Artists"),
+///         String::from("Nina Simone"),
+///     ],
+///     Message::SegmentSelected,
+/// )
+/// .separator("/");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Breadcrumbs<Message, Renderer: self::Renderer> {
+    segments: Vec<String>,
+    on_select: Box<dyn Fn(usize) -> Message>,
+    separator: String,
+    spacing: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: Renderer::Style,
+}
+
+impl<Message, Renderer: self::Renderer> Breadcrumbs<Message, Renderer> {
+    /// Creates new [`Breadcrumbs`] with the given segments and a function
+    /// that produces a message when a segment is selected.
+    pub fn new<F>(segments: Vec<String>, on_select: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        Breadcrumbs {
+            segments,
+            on_select: Box::new(on_select),
+            separator: String::from(Renderer::DEFAULT_SEPARATOR),
+            spacing: Renderer::DEFAULT_SPACING,
+            text_size: None,
+            font: Default::default(),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the separator of the [`Breadcrumbs`].
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the spacing around the separators of the [`Breadcrumbs`].
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the [`Breadcrumbs`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Breadcrumbs`].
+    ///
+    /// [`Font`]: crate::widget::text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Breadcrumbs`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer>
+    for Breadcrumbs<Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        let (separator_width, separator_height) = renderer.measure(
+            &self.separator,
+            text_size,
+            self.font,
+            Size::INFINITY,
+        );
+
+        let gap = separator_width + f32::from(self.spacing) * 2.0;
+
+        let mut x = 0.0;
+        let mut height = separator_height;
+
+        let children: Vec<layout::Node> = self
+            .segments
+            .iter()
+            .map(|segment| {
+                let (width, segment_height) = renderer.measure(
+                    segment,
+                    text_size,
+                    self.font,
+                    Size::INFINITY,
+                );
+
+                let mut node =
+                    layout::Node::new(Size::new(width, segment_height));
+
+                node.move_to(Point::new(x, 0.0));
+                x += width + gap;
+                height = height.max(segment_height);
+
+                node
+            })
+            .collect();
+
+        let width = if children.is_empty() { 0.0 } else { x - gap };
+
+        layout::Node::with_children(
+            limits.resolve(Size::new(width, height)),
+            children,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            let current = self.segments.len().saturating_sub(1);
+
+            let clicked = layout
+                .children()
+                .take(current)
+                .position(|segment| segment.bounds().contains(cursor_position));
+
+            if let Some(index) = clicked {
+                messages.push((self.on_select)(index));
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout,
+            cursor_position,
+            &self.segments,
+            &self.separator,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.segments.hash(state);
+        self.separator.hash(state);
+        self.spacing.hash(state);
+        self.text_size.hash(state);
+    }
+}
+
+/// The renderer of some [`Breadcrumbs`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use [`Breadcrumbs`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default separator of some [`Breadcrumbs`].
+    const DEFAULT_SEPARATOR: &'static str;
+
+    /// The default spacing around the separators of some [`Breadcrumbs`].
+    const DEFAULT_SPACING: u16;
+
+    /// Draws some [`Breadcrumbs`].
+    ///
+    /// It receives:
+    ///   * the [`Layout`] of the [`Breadcrumbs`], with a child for every
+    ///     segment
+    ///   * the cursor position
+    ///   * the segments of the [`Breadcrumbs`]
+    ///   * the separator between the segments
+    ///   * the text size
+    ///   * the font
+    ///   * the style of the [`Breadcrumbs`]
+    fn draw(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        segments: &[String],
+        separator: &str,
+        text_size: u16,
+        font: Self::Font,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Breadcrumbs<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        breadcrumbs: Breadcrumbs<Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(breadcrumbs)
+    }
+}
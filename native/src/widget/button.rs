@@ -4,6 +4,7 @@
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
+use crate::widget::Operation;
 use crate::{
     Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Widget,
 };
@@ -223,6 +224,15 @@ where
         self.width.hash(state);
         self.content.hash_layout(state);
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .operate(layout.children().next().unwrap(), operation);
+    }
 }
 
 /// The renderer of a [`Button`].
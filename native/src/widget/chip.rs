@@ -0,0 +1,324 @@
+//! Display compact labels that can be selected or pressed.
+//!
+//! A [`Chip`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::text;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A compact label, like a tag or a filter, that can optionally be selected
+/// or pressed.
+///
+/// # Example
+/// ```
+/// # use iced_native::{chip, renderer::Null};
+/// #
+/// # pub type Chip<'a, Message> = iced_native::Chip<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// pub enum Message {
+///     HdToggled,
+/// }
+///
+/// let mut state = chip::State::new();
+/// let only_hd = true;
+///
+/// let chip = Chip::new(&mut state, "HD")
+///     .selected(only_hd)
+///     .on_press(Message::HdToggled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Chip<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    label: String,
+    on_press: Option<Message>,
+    is_selected: bool,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> Chip<'a, Message, Renderer> {
+    /// Creates a new [`Chip`] with some local [`State`] and the given label.
+    pub fn new(state: &'a mut State, label: impl Into<String>) -> Self {
+        Chip {
+            state,
+            label: label.into(),
+            on_press: None,
+            is_selected: false,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Chip`] is pressed.
+    ///
+    /// Like a button, the message is produced once the mouse button is
+    /// released over the [`Chip`] it was pressed on.
+    ///
+    /// A [`Chip`] without a message is not interactive.
+    pub fn on_press(mut self, msg: Message) -> Self {
+        self.on_press = Some(msg);
+        self
+    }
+
+    /// Sets whether the [`Chip`] is selected or not.
+    pub fn selected(mut self, is_selected: bool) -> Self {
+        self.is_selected = is_selected;
+        self
+    }
+
+    /// Sets the padding of the [`Chip`].
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`Chip`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the [`Font`] of the label of the [`Chip`].
+    ///
+    /// [`Font`]: crate::widget::text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Chip`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`Chip`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_pressed: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Chip<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+
+        let (width, height) = renderer.measure(
+            &self.label,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            Size::INFINITY,
+        );
+
+        let size = Size::new(width + padding * 2.0, height + padding);
+
+        layout::Node::new(limits.resolve(size))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if self.on_press.is_some()
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                self.state.is_pressed = true;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(on_press) = self.on_press.clone() {
+                    if self.state.is_pressed {
+                        self.state.is_pressed = false;
+
+                        if layout.bounds().contains(cursor_position) {
+                            messages.push(on_press);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            &self.label,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            self.is_selected,
+            self.on_press.is_some(),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.label.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+}
+
+/// The renderer of a [`Chip`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Chip`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default padding of a [`Chip`].
+    const DEFAULT_PADDING: u16;
+
+    /// Draws a [`Chip`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Chip`]
+    ///   * the cursor position
+    ///   * the label of the [`Chip`]
+    ///   * the text size of the label
+    ///   * the font of the label
+    ///   * whether the [`Chip`] is selected or not
+    ///   * whether the [`Chip`] can be pressed or not
+    ///   * the style of the [`Chip`]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        label: &str,
+        text_size: u16,
+        font: Self::Font,
+        is_selected: bool,
+        is_pressable: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Chip<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        chip: Chip<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(chip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+
+    fn click(
+        state: &mut State,
+        press: Point,
+        release: Point,
+    ) -> Vec<&'static str> {
+        let mut messages = Vec::new();
+        let renderer = Null::new();
+
+        let events = [
+            (mouse::Event::ButtonPressed(mouse::Button::Left), press),
+            (mouse::Event::ButtonReleased(mouse::Button::Left), release),
+        ];
+
+        for (event, cursor_position) in events.iter().cloned() {
+            let mut chip: Chip<'_, _, Null> =
+                Chip::new(state, "HD").on_press("pressed");
+            let node = layout::Node::new(Size::new(20.0, 10.0));
+
+            let _ = chip.on_event(
+                Event::Mouse(event),
+                Layout::new(&node),
+                cursor_position,
+                &mut messages,
+                &renderer,
+                None,
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    fn press_fires_on_release_inside() {
+        let mut state = State::new();
+
+        assert_eq!(
+            click(&mut state, Point::ORIGIN, Point::ORIGIN),
+            vec!["pressed"]
+        );
+    }
+
+    #[test]
+    fn press_is_cancelled_by_release_outside() {
+        let mut state = State::new();
+        let outside = Point::new(100.0, 100.0);
+
+        assert!(click(&mut state, Point::ORIGIN, outside).is_empty());
+        assert!(click(&mut state, outside, Point::ORIGIN).is_empty());
+    }
+}
@@ -4,6 +4,7 @@ use std::hash::Hash;
 use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
+use crate::widget::{Id, Operation};
 use crate::{
     Align, Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Widget,
 };
@@ -15,6 +16,7 @@ use std::u32;
 /// It is normally used for alignment purposes.
 #[allow(missing_debug_implementations)]
 pub struct Container<'a, Message, Renderer: self::Renderer> {
+    id: Option<Id>,
     padding: u16,
     width: Length,
     height: Length,
@@ -36,6 +38,7 @@ where
         T: Into<Element<'a, Message, Renderer>>,
     {
         Container {
+            id: None,
             padding: 0,
             width: Length::Shrink,
             height: Length::Shrink,
@@ -48,6 +51,12 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`Container`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the padding of the [`Container`].
     pub fn padding(mut self, units: u16) -> Self {
         self.padding = units;
@@ -203,6 +212,18 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.content.overlay(layout.children().next().unwrap())
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let content = &mut self.content;
+
+        operation.container(self.id.as_ref(), &mut |operation| {
+            content.operate(layout.children().next().unwrap(), operation);
+        });
+    }
 }
 
 /// The renderer of a [`Container`].
@@ -0,0 +1,184 @@
+//! Show a specific mouse cursor over some content.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Widget,
+};
+
+use std::hash::Hash;
+
+/// An element that shows a specific mouse cursor while it is hovered.
+///
+/// The cursor requested by the content takes precedence, so buttons and text
+/// inputs inside a [`CursorArea`] keep their own cursors.
+///
+/// # Example
+/// ```
+/// # use iced_native::{mouse, renderer::Null, CursorArea};
+/// #
+/// # pub type Text = iced_native::Text<Null>;
+/// let is_idle = true;
+///
+/// let video: CursorArea<'_, (), Null> =
+///     CursorArea::new(Text::new("Fullscreen video")).interaction(if is_idle {
+///         mouse::Interaction::Hidden
+///     } else {
+///         mouse::Interaction::Idle
+///     });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct CursorArea<'a, Message, Renderer> {
+    interaction: mouse::Interaction,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> CursorArea<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`CursorArea`] with the given content.
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        CursorArea {
+            interaction: mouse::Interaction::default(),
+            content: content.into(),
+        }
+    }
+
+    /// Sets the [`mouse::Interaction`] shown while the [`CursorArea`] is
+    /// hovered.
+    pub fn interaction(mut self, interaction: mouse::Interaction) -> Self {
+        self.interaction = interaction;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for CursorArea<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.content.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            defaults,
+            layout.bounds(),
+            cursor_position,
+            viewport,
+            self.interaction,
+            &self.content,
+            layout.children().next().unwrap(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .operate(layout.children().next().unwrap(), operation);
+    }
+}
+
+/// The renderer of a [`CursorArea`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`CursorArea`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`CursorArea`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`CursorArea`]
+    ///   * the cursor position
+    ///   * the [`mouse::Interaction`] to show while the [`CursorArea`] is
+    ///     hovered, unless its content requests another one
+    ///   * the content of the [`CursorArea`] and its layout
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        interaction: mouse::Interaction,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<CursorArea<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        cursor_area: CursorArea<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(cursor_area)
+    }
+}
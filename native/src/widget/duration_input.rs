@@ -0,0 +1,414 @@
+//! Enter durations and timestamps, like `01:02:03.456`.
+//!
+//! A [`DurationInput`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::text_input::{self, TextInput};
+use crate::widget::{Id, Operation};
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Widget,
+};
+
+use std::hash::Hash;
+use std::time::Duration;
+
+/// A field that can be filled with a [`Duration`].
+///
+/// A [`DurationInput`] shows its value as `hh:mm:ss.fff`, omitting the hours
+/// when they are zero. Typed text is produced once it can be [`parse`]d and
+/// does not exceed the maximum of the [`DurationInput`].
+///
+/// # Example
+/// ```
+/// # use iced_native::{duration_input, renderer::Null};
+/// #
+/// # pub type DurationInput<'a, Message> =
+/// #     iced_native::DurationInput<'a, Message, Null>;
+/// # use std::time::Duration;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LoopStartChanged(Duration),
+/// }
+///
+/// let mut state = duration_input::State::new();
+/// let loop_start = Duration::from_millis(83_250);
+///
+/// let input = DurationInput::new(
+///     &mut state,
+///     loop_start,
+///     Message::LoopStartChanged,
+/// )
+/// .max(Duration::from_secs(3 * 60));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DurationInput<'a, Message, Renderer: text_input::Renderer> {
+    text_input: TextInput<'a, String, Renderer>,
+    text: &'a mut Option<String>,
+    max: Option<Duration>,
+    on_change: Box<dyn Fn(Duration) -> Message>,
+}
+
+impl<'a, Message, Renderer> DurationInput<'a, Message, Renderer>
+where
+    Renderer: text_input::Renderer,
+{
+    /// Creates a new [`DurationInput`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - the current value
+    /// - a function that produces a message when the value changes
+    pub fn new<F>(state: &'a mut State, value: Duration, on_change: F) -> Self
+    where
+        F: 'static + Fn(Duration) -> Message,
+    {
+        let State { text_input, text } = state;
+
+        let content = match text.as_deref() {
+            Some(text) if text_input.is_focused() => String::from(text),
+            _ => format(value),
+        };
+
+        DurationInput {
+            text_input: TextInput::new(
+                text_input,
+                "00:00.000",
+                &content,
+                String::from,
+            ),
+            text,
+            max: None,
+            on_change: Box::new(on_change),
+        }
+    }
+
+    /// Sets the maximum value of the [`DurationInput`].
+    pub fn max(mut self, max: Duration) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`DurationInput`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`DurationInput`].
+    ///
+    /// [`Font`]: crate::widget::text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the width of the [`DurationInput`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the maximum width of the [`DurationInput`].
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.text_input = self.text_input.max_width(max_width);
+        self
+    }
+
+    /// Sets the padding of the [`DurationInput`].
+    pub fn padding(mut self, units: u16) -> Self {
+        self.text_input = self.text_input.padding(units);
+        self
+    }
+
+    /// Sets the text size of the [`DurationInput`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the style of the [`DurationInput`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for DurationInput<'a, Message, Renderer>
+where
+    Renderer: text_input::Renderer,
+{
+    fn width(&self) -> Length {
+        Widget::<String, Renderer>::width(&self.text_input)
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        Widget::<String, Renderer>::layout(&self.text_input, renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let mut edits = Vec::new();
+
+        let status = self.text_input.on_event(
+            event,
+            layout,
+            cursor_position,
+            &mut edits,
+            renderer,
+            clipboard,
+        );
+
+        for text in edits {
+            let max = self.max;
+
+            let value = parse(&text)
+                .filter(|value| max.map(|max| *value <= max).unwrap_or(true));
+
+            if let Some(value) = value {
+                messages.push((self.on_change)(value));
+            }
+
+            *self.text = Some(text);
+        }
+
+        if !self.text_input.state().is_focused() {
+            *self.text = None;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.text_input
+            .draw(renderer, layout, cursor_position, None)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        Widget::<String, Renderer>::hash_layout(&self.text_input, state);
+    }
+
+    fn operate(
+        &mut self,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.text_input.operate(operation);
+    }
+}
+
+impl<'a, Message, Renderer> From<DurationInput<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text_input::Renderer,
+{
+    fn from(
+        duration_input: DurationInput<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(duration_input)
+    }
+}
+
+/// The state of a [`DurationInput`].
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    text_input: text_input::State,
+    text: Option<String>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`DurationInput`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`State`], representing a focused [`DurationInput`].
+    pub fn focused() -> Self {
+        Self {
+            text_input: text_input::State::focused(),
+            text: None,
+        }
+    }
+
+    /// Returns whether the [`DurationInput`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.text_input.is_focused()
+    }
+}
+
+/// Formats a [`Duration`] as `hh:mm:ss.fff`, omitting the hours when they are
+/// zero.
+pub fn format(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let milliseconds = duration.subsec_millis();
+
+    let (hours, minutes, seconds) =
+        (seconds / 3600, seconds / 60 % 60, seconds % 60);
+
+    if hours > 0 {
+        format!(
+            "{:02}:{:02}:{:02}.{:03}",
+            hours, minutes, seconds, milliseconds
+        )
+    } else {
+        format!("{:02}:{:02}.{:03}", minutes, seconds, milliseconds)
+    }
+}
+
+/// Parses a [`Duration`] written as `hh:mm:ss.fff`.
+///
+/// The hours, the minutes and the fraction of a second are optional, so
+/// `90`, `1:30` and `00:01:30.000` are all valid and equal. The leading
+/// component may exceed its usual range, like the minutes in `90:00`.
+pub fn parse(text: &str) -> Option<Duration> {
+    let text = text.trim();
+
+    let (clock, fraction) = match text.find('.') {
+        Some(index) => (&text[..index], Some(&text[index + 1..])),
+        None => (text, None),
+    };
+
+    let mut components = clock.rsplit(':');
+    let mut seconds: u64 = 0;
+
+    for (index, component) in components.by_ref().take(3).enumerate() {
+        if component.is_empty()
+            || !component.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let value: u64 = component.parse().ok()?;
+        let is_leading = clock.split(':').count() == index + 1;
+
+        if !is_leading && value >= 60 {
+            return None;
+        }
+
+        seconds =
+            seconds.checked_add(value.checked_mul(60u64.pow(index as u32))?)?;
+    }
+
+    if components.next().is_some() {
+        return None;
+    }
+
+    let nanoseconds = match fraction {
+        Some(fraction) => {
+            if fraction.is_empty()
+                || fraction.len() > 9
+                || !fraction.bytes().all(|b| b.is_ascii_digit())
+            {
+                return None;
+            }
+
+            let digits: u32 = fraction.parse().ok()?;
+
+            digits * 10u32.pow(9 - fraction.len() as u32)
+        }
+        None => 0,
+    };
+
+    Some(Duration::new(seconds, nanoseconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_optional_components() {
+        let expected = Some(Duration::from_secs(90));
+
+        assert_eq!(parse("90"), expected);
+        assert_eq!(parse("1:30"), expected);
+        assert_eq!(parse("00:01:30.000"), expected);
+        assert_eq!(parse("  1:30  "), expected);
+    }
+
+    #[test]
+    fn parse_lets_leading_component_exceed_its_range() {
+        assert_eq!(parse("75"), Some(Duration::from_secs(75)));
+        assert_eq!(parse("90:00"), Some(Duration::from_secs(90 * 60)));
+        assert_eq!(parse("100:00:00"), Some(Duration::from_secs(360_000)));
+
+        assert_eq!(parse("1:60"), None);
+        assert_eq!(parse("1:60:00"), None);
+    }
+
+    #[test]
+    fn parse_accepts_one_to_nine_fraction_digits() {
+        assert_eq!(parse("1.5"), Some(Duration::from_millis(1_500)));
+        assert_eq!(parse("0.05"), Some(Duration::from_millis(50)));
+        assert_eq!(
+            parse("0.123456789"),
+            Some(Duration::from_nanos(123_456_789))
+        );
+
+        assert_eq!(parse("1."), None);
+        assert_eq!(parse("0.1234567890"), None);
+        assert_eq!(parse("0.-1"), None);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_input() {
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("1:2:3:4"), None);
+        assert_eq!(parse("1::30"), None);
+        assert_eq!(parse(":30"), None);
+        assert_eq!(parse("+30"), None);
+        assert_eq!(parse("1:3a"), None);
+        assert_eq!(parse("18446744073709551615:00"), None);
+    }
+
+    #[test]
+    fn format_omits_hours_when_zero() {
+        assert_eq!(format(Duration::from_millis(0)), "00:00.000");
+        assert_eq!(format(Duration::from_millis(61_005)), "01:01.005");
+        assert_eq!(format(Duration::from_secs(3_600)), "01:00:00.000");
+        assert_eq!(format(Duration::from_secs(360_000)), "100:00:00.000");
+    }
+
+    #[test]
+    fn format_and_parse_round_trip() {
+        for milliseconds in &[0, 1, 999, 59_999, 60_000, 3_599_999, 86_400_001]
+        {
+            let duration = Duration::from_millis(*milliseconds);
+
+            assert_eq!(parse(&format(duration)), Some(duration));
+        }
+
+        // The fraction is truncated to milliseconds
+        assert_eq!(
+            parse(&format(Duration::from_nanos(1_999_999_999))),
+            Some(Duration::from_millis(1_999))
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+use std::sync::atomic::{self, AtomicUsize};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The identifier of a [`Widget`].
+///
+/// An [`Operation`] can use it to find a specific [`Widget`] in the user
+/// interface.
+///
+/// [`Widget`]: crate::Widget
+/// [`Operation`]: crate::widget::Operation
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(Internal);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Internal {
+    Unique(usize),
+    Custom(Cow<'static, str>),
+}
+
+impl Id {
+    /// Creates a custom [`Id`] with the given name.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Id(Internal::Custom(name.into()))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This is useful to identify widgets that are built in a loop, where
+    /// naming each one would be cumbersome. Keep the [`Id`] in your
+    /// application state to use it later.
+    pub fn unique() -> Self {
+        let id = NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed);
+
+        Id(Internal::Unique(id))
+    }
+}
@@ -0,0 +1,349 @@
+//! Zoom into images and pan around them.
+//!
+//! An [`ImageViewer`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::image;
+use crate::layout;
+use crate::mouse::{self, click};
+use crate::viewport::Viewport;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Vector,
+    Widget,
+};
+
+use std::hash::Hash;
+
+/// A frame that displays an image that can be zoomed with the mouse wheel
+/// and panned by dragging it.
+///
+/// The image fits the [`ImageViewer`] initially. Double-clicking it switches
+/// between fitting the image and showing it at its original size. The image
+/// cannot be panned beyond its edges.
+///
+/// # Example
+/// ```
+/// # use iced_native::{image, image_viewer, ImageViewer};
+/// #
+/// let mut state = image_viewer::State::new();
+///
+/// let viewer = ImageViewer::new(&mut state, image::Handle::from_path("poster.png"))
+///     .max_scale(4.0);
+/// ```
+#[derive(Debug)]
+pub struct ImageViewer<'a> {
+    state: &'a mut State,
+    handle: image::Handle,
+    width: Length,
+    height: Length,
+    min_scale: f32,
+    max_scale: f32,
+    scale_step: f32,
+}
+
+impl<'a> ImageViewer<'a> {
+    /// Creates a new [`ImageViewer`] with the given [`State`] and image
+    /// [`Handle`].
+    ///
+    /// [`Handle`]: image::Handle
+    pub fn new(state: &'a mut State, handle: image::Handle) -> Self {
+        ImageViewer {
+            state,
+            handle,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            min_scale: 1.0,
+            max_scale: 8.0,
+            scale_step: 0.1,
+        }
+    }
+
+    /// Sets the width of the [`ImageViewer`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ImageViewer`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the minimum scale of the [`ImageViewer`], relative to the scale
+    /// that fits the image.
+    ///
+    /// The default is `1.0`, which does not let the image get any smaller
+    /// than fitting the [`ImageViewer`].
+    pub fn min_scale(mut self, min_scale: f32) -> Self {
+        self.min_scale = min_scale;
+        self
+    }
+
+    /// Sets the maximum scale of the [`ImageViewer`], relative to the scale
+    /// that fits the image.
+    pub fn max_scale(mut self, max_scale: f32) -> Self {
+        self.max_scale = max_scale;
+        self
+    }
+
+    /// Sets how much a line of the mouse wheel changes the scale of the
+    /// [`ImageViewer`], relative to the current scale.
+    pub fn scale_step(mut self, scale_step: f32) -> Self {
+        self.scale_step = scale_step;
+        self
+    }
+
+    /// Returns the size of the image when it fits the given bounds.
+    fn fitted_size<Renderer: self::Renderer>(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+    ) -> Size {
+        let (width, height) = renderer.dimensions(&self.handle);
+        let (width, height) = (width as f32, height as f32);
+
+        let scale = (bounds.width / width).min(bounds.height / height);
+
+        Size::new(width * scale, height * scale)
+    }
+
+    /// Scales the image to the given scale, within the limits of the
+    /// [`ImageViewer`], keeping the point under the cursor in place.
+    fn scale_to(
+        &mut self,
+        scale: f32,
+        bounds: Rectangle,
+        fitted_size: Size,
+        cursor_position: Point,
+    ) {
+        let scale = scale.max(self.min_scale).min(self.max_scale);
+
+        self.state.viewport.scale_to(
+            scale,
+            bounds,
+            fitted_size,
+            cursor_position,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for ImageViewer<'a>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (width, height) = renderer.dimensions(&self.handle);
+
+        let size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(Size::new(width as f32, height as f32));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+        let fitted_size = self.fitted_size(renderer, bounds.size());
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if is_mouse_over =>
+            {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                };
+
+                let scale =
+                    self.state.scale() * (1.0 + self.scale_step).powf(lines);
+
+                self.scale_to(scale, bounds, fitted_size, cursor_position);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if is_mouse_over =>
+            {
+                let click =
+                    mouse::Click::new(cursor_position, self.state.last_click);
+
+                if let click::Kind::Double = click.kind() {
+                    if (self.state.scale() - 1.0).abs() > f32::EPSILON {
+                        self.state.fit();
+                    } else {
+                        let (width, _) = renderer.dimensions(&self.handle);
+                        let original = width as f32 / fitted_size.width;
+
+                        self.scale_to(
+                            original,
+                            bounds,
+                            fitted_size,
+                            cursor_position,
+                        );
+                    }
+                }
+
+                self.state.last_click = Some(click);
+                self.state.grabbed_at = Some(cursor_position);
+                self.state.starting_offset = self.state.viewport.offset();
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                match self.state.grabbed_at {
+                    Some(grabbed_at) => {
+                        self.state.viewport.pan_to(
+                            self.state.starting_offset
+                                + (cursor_position - grabbed_at),
+                            bounds.size(),
+                            fitted_size,
+                        );
+
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if self.state.grabbed_at.is_some() =>
+            {
+                self.state.grabbed_at = None;
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let fitted_size = self.fitted_size(renderer, bounds.size());
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            self.state.viewport.content_bounds(bounds, fitted_size),
+            cursor_position,
+            self.handle.clone(),
+            self.state.is_grabbed(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.handle.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of an [`ImageViewer`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use an [`ImageViewer`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: image::Renderer {
+    /// Draws an [`ImageViewer`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ImageViewer`]
+    ///   * the bounds of the zoomed and panned image, which may exceed the
+    ///     bounds of the [`ImageViewer`]
+    ///   * the cursor position
+    ///   * the handle of the image
+    ///   * whether the image is being dragged or not
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        image_bounds: Rectangle,
+        cursor_position: Point,
+        handle: image::Handle,
+        is_grabbed: bool,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ImageViewer<'a>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+{
+    fn from(viewer: ImageViewer<'a>) -> Element<'a, Message, Renderer> {
+        Element::new(viewer)
+    }
+}
+
+/// The state of an [`ImageViewer`].
+#[derive(Debug, Clone, Copy)]
+pub struct State {
+    viewport: Viewport,
+    grabbed_at: Option<Point>,
+    starting_offset: Vector,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an image that fits its
+    /// [`ImageViewer`].
+    pub fn new() -> Self {
+        State {
+            viewport: Viewport::new(),
+            grabbed_at: None,
+            starting_offset: Vector::new(0.0, 0.0),
+            last_click: None,
+        }
+    }
+
+    /// Returns the current scale of the image, relative to the scale that
+    /// fits its [`ImageViewer`].
+    pub fn scale(&self) -> f32 {
+        self.viewport.scale()
+    }
+
+    /// Returns whether the image is being dragged or not.
+    pub fn is_grabbed(&self) -> bool {
+        self.grabbed_at.is_some()
+    }
+
+    /// Makes the image fit its [`ImageViewer`] again.
+    pub fn fit(&mut self) {
+        self.viewport.fit();
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
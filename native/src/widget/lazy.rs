@@ -0,0 +1,410 @@
+//! Avoid rebuilding parts of your user interface that have not changed.
+//!
+//! A [`Lazy`] widget stores its contents in a [`Cache`] and only runs its
+//! view logic again when a dependency changes. The layout of the contents is
+//! memoized as well, so unrelated updates elsewhere in the user interface do
+//! not need to lay it out again.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::overlay;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Widget,
+};
+
+use std::cell::RefCell;
+use std::hash::{Hash, Hasher as _};
+
+/// A widget that only rebuilds its contents when its dependency changes.
+///
+/// The [`Cache`] of a [`Lazy`] widget owns its contents. Therefore, the
+/// contents cannot borrow any data, including the local state of stateful
+/// widgets like a [`Button`]. They can contain any widget that owns all of
+/// its data, like a [`Text`], an [`Image`] or a [`Checkbox`].
+///
+/// [`Button`]: crate::Button
+/// [`Text`]: crate::Text
+/// [`Image`]: crate::Image
+/// [`Checkbox`]: crate::Checkbox
+///
+/// # Example
+/// ```
+/// # use iced_native::{lazy, renderer::Null, Lazy};
+/// #
+/// # pub type Checkbox<Message> = iced_native::Checkbox<Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(usize, bool),
+/// }
+///
+/// let mut cache = lazy::Cache::new();
+/// let playlist = vec![(String::from("intro.mkv"), true), (String::from("outro.mkv"), false)];
+///
+/// let lazy: Lazy<'_, Message, Null> = Lazy::new(&mut cache, &playlist, || {
+///     playlist
+///         .iter()
+///         .enumerate()
+///         .fold(iced_native::Column::new(), |column, (i, (name, is_queued))| {
+///             column.push(Checkbox::new(*is_queued, name.clone(), move |queued| {
+///                 Message::Toggled(i, queued)
+///             }))
+///         })
+///         .into()
+/// });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Lazy<'a, Message: 'static, Renderer: 'static> {
+    cache: &'a mut Cache<Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Lazy<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`Lazy`] widget with the given [`Cache`], dependency and
+    /// view logic.
+    ///
+    /// The `view` closure will only be called when the [`Cache`] is empty or
+    /// the hash of the `dependency` differs from the one used to fill it.
+    pub fn new<T>(
+        cache: &'a mut Cache<Message, Renderer>,
+        dependency: &T,
+        view: impl FnOnce() -> Element<'static, Message, Renderer>,
+    ) -> Self
+    where
+        T: Hash + ?Sized,
+    {
+        let hash = {
+            let mut hasher = Hasher::default();
+            dependency.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        if cache.element.is_none() || cache.hash != hash {
+            let element = view();
+
+            cache.element = Some(element);
+            cache.hash = hash;
+            *cache.layout.get_mut() = None;
+        }
+
+        Lazy { cache }
+    }
+
+    fn element(&self) -> &Element<'static, Message, Renderer> {
+        self.cache
+            .element
+            .as_ref()
+            .expect("Lazy contents are built on creation")
+    }
+
+    fn element_mut(&mut self) -> &mut Element<'static, Message, Renderer> {
+        self.cache
+            .element
+            .as_mut()
+            .expect("Lazy contents are built on creation")
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Lazy<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.element().width()
+    }
+
+    fn height(&self) -> Length {
+        self.element().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        if let Some((cached_limits, node)) = self.cache.layout.borrow().as_ref()
+        {
+            if cached_limits == limits {
+                return node.clone();
+            }
+        }
+
+        let node = self.element().layout(renderer, limits);
+
+        *self.cache.layout.borrow_mut() = Some((*limits, node.clone()));
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.element().draw(
+            renderer,
+            defaults,
+            layout,
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.cache.hash.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.element_mut().on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.element_mut().overlay(layout)
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.element_mut().operate(layout, operation);
+    }
+}
+
+/// The memoized contents of a [`Lazy`] widget.
+pub struct Cache<Message: 'static, Renderer: 'static> {
+    hash: u64,
+    element: Option<Element<'static, Message, Renderer>>,
+    layout: RefCell<Option<(layout::Limits, layout::Node)>>,
+}
+
+impl<Message, Renderer> Cache<Message, Renderer> {
+    /// Creates a new empty [`Cache`].
+    pub fn new() -> Self {
+        Cache {
+            hash: 0,
+            element: None,
+            layout: RefCell::new(None),
+        }
+    }
+
+    /// Clears the [`Cache`], forcing the contents to be rebuilt the next time
+    /// it is used.
+    pub fn clear(&mut self) {
+        self.element = None;
+        *self.layout.get_mut() = None;
+    }
+}
+
+impl<Message, Renderer> Default for Cache<Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Renderer> std::fmt::Debug for Cache<Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cache")
+            .field("hash", &self.hash)
+            .field("is_empty", &self.element.is_none())
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> From<Lazy<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        lazy: Lazy<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(lazy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{mouse, Cache as UiCache, Checkbox, Column, Text};
+    use crate::{Size, UserInterface};
+
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// A widget that counts how many times it is laid out.
+    struct Probe {
+        layouts: Rc<Cell<usize>>,
+    }
+
+    impl<Message> Widget<Message, Null> for Probe {
+        fn width(&self) -> Length {
+            Length::Units(10)
+        }
+
+        fn height(&self) -> Length {
+            Length::Units(10)
+        }
+
+        fn layout(
+            &self,
+            _renderer: &Null,
+            _limits: &layout::Limits,
+        ) -> layout::Node {
+            self.layouts.set(self.layouts.get() + 1);
+
+            layout::Node::new(Size::new(10.0, 10.0))
+        }
+
+        fn draw(
+            &self,
+            _renderer: &mut Null,
+            _defaults: &(),
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _viewport: &Rectangle,
+        ) {
+        }
+
+        fn hash_layout(&self, _state: &mut Hasher) {}
+    }
+
+    #[test]
+    fn cache_hit_skips_rebuilding_and_relayout() {
+        let mut renderer = Null::new();
+        let mut cache = Cache::<(), Null>::default();
+        let mut ui_cache = UiCache::new();
+
+        let views = Rc::new(Cell::new(0));
+        let layouts = Rc::new(Cell::new(0));
+
+        for label in &["a", "ab", "abc"] {
+            let lazy = Lazy::new(&mut cache, &0, || {
+                views.set(views.get() + 1);
+
+                Element::new(Probe {
+                    layouts: layouts.clone(),
+                })
+            });
+
+            // The sibling changes the layout hash of the whole interface
+            let root = Column::new().push(lazy).push(Text::new(*label));
+
+            let user_interface = UserInterface::build(
+                root,
+                Size::new(100.0, 100.0),
+                ui_cache,
+                &mut renderer,
+            );
+
+            ui_cache = user_interface.into_cache();
+        }
+
+        assert_eq!(views.get(), 1);
+        assert_eq!(layouts.get(), 1);
+    }
+
+    #[test]
+    fn dependency_change_rebuilds() {
+        let mut cache = Cache::<(), Null>::new();
+        let mut seen = Vec::new();
+
+        for dependency in &[1, 1, 2] {
+            let _ = Lazy::new(&mut cache, dependency, || {
+                seen.push(*dependency);
+
+                Text::new(dependency.to_string()).into()
+            });
+        }
+
+        assert_eq!(seen, vec![1, 2]);
+    }
+
+    #[test]
+    fn panicking_view_leaves_cache_usable() {
+        let mut cache = Cache::<(), Null>::new();
+
+        let result =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                let _ = Lazy::new(&mut cache, &1, || panic!("View failed"));
+            }));
+
+        assert!(result.is_err());
+
+        let mut views = 0;
+        let _ = Lazy::new(&mut cache, &1, || {
+            views += 1;
+
+            Text::new("Recovered").into()
+        });
+
+        assert_eq!(views, 1);
+    }
+
+    #[test]
+    fn contents_handle_events() {
+        let mut renderer = Null::new();
+        let mut cache = Cache::<bool, Null>::default();
+        let mut ui_cache = UiCache::new();
+        let mut messages = Vec::new();
+
+        let events = [
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ];
+
+        for event in events.iter() {
+            let lazy = Lazy::new(&mut cache, &(), || {
+                Checkbox::new(false, "Queued", |is_queued| is_queued).into()
+            });
+
+            let mut user_interface = UserInterface::build(
+                lazy,
+                Size::new(100.0, 100.0),
+                ui_cache,
+                &mut renderer,
+            );
+
+            let _ = user_interface.update(
+                std::slice::from_ref(event),
+                Point::new(5.0, 5.0),
+                None,
+                &renderer,
+                &mut messages,
+            );
+
+            ui_cache = user_interface.into_cache();
+        }
+
+        assert_eq!(messages, vec![true]);
+    }
+}
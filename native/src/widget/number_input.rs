@@ -0,0 +1,563 @@
+//! Enter numbers within a range, typing them or stepping with spinners.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::text_input::{self, TextInput};
+use crate::widget::{Id, Operation};
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+
+/// A field that can be filled with a number within a range, with spinners to
+/// increment and decrement it.
+///
+/// The text typed in a [`NumberInput`] is only produced once it is a valid
+/// number within its range. The up and down arrow keys step the value when
+/// the [`NumberInput`] is focused.
+///
+/// Any primitive [`Number`] can be entered, including `i8`, `u64` and `f32`.
+/// Steps are computed with `f64`, so stepping integers larger than `2^53`
+/// may round them.
+///
+/// # Example
+/// ```
+/// # use iced_native::{number_input, renderer::Null};
+/// #
+/// # pub type NumberInput<'a, T, Message> =
+/// #     iced_native::NumberInput<'a, T, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     BitrateChanged(u32),
+/// }
+///
+/// let mut state = number_input::State::new();
+/// let bitrate = 4_000;
+///
+/// let input = NumberInput::new(
+///     &mut state,
+///     bitrate,
+///     500..=50_000,
+///     Message::BitrateChanged,
+/// )
+/// .step(500);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message, Renderer: self::Renderer> {
+    text_input: TextInput<'a, String, Renderer>,
+    text: &'a mut Option<String>,
+    value: T,
+    range: RangeInclusive<T>,
+    step: T,
+    on_change: Box<dyn Fn(T) -> Message>,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Number,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - the current value
+    /// - an inclusive range of possible values
+    /// - a function that produces a message when the value changes
+    pub fn new<F>(
+        state: &'a mut State,
+        value: T,
+        range: RangeInclusive<T>,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        let State { text_input, text } = state;
+
+        let value = if value >= *range.start() {
+            value
+        } else {
+            *range.start()
+        };
+
+        let value = if value <= *range.end() {
+            value
+        } else {
+            *range.end()
+        };
+
+        let content = match text.as_deref() {
+            Some(text) if text_input.is_focused() => String::from(text),
+            _ => value.to_string(),
+        };
+
+        NumberInput {
+            text_input: TextInput::new(text_input, "", &content, String::from),
+            text,
+            value,
+            range,
+            step: T::ONE,
+            on_change: Box::new(on_change),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    ///
+    /// The spinners and the arrow keys change the value by this amount.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`NumberInput`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`NumberInput`].
+    ///
+    /// [`Font`]: crate::widget::text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the maximum width of the [`NumberInput`].
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.text_input = self.text_input.max_width(max_width);
+        self
+    }
+
+    /// Sets the padding of the [`NumberInput`].
+    pub fn padding(mut self, units: u16) -> Self {
+        self.text_input = self.text_input.padding(units);
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    ///
+    /// The spinners are sized accordingly.
+    pub fn size(mut self, size: u16) -> Self {
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the style of the field of the [`NumberInput`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as text_input::Renderer>::Style>,
+    ) -> Self {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style of the spinners of the [`NumberInput`].
+    pub fn spinner_style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Number,
+    Renderer: self::Renderer,
+{
+    fn stepped(&self, steps: f64) -> Option<T> {
+        let start = self.range.start().to_f64()?;
+        let end = self.range.end().to_f64()?;
+
+        let value = (self.value.to_f64()? + steps * self.step.to_f64()?)
+            .max(start)
+            .min(end);
+
+        T::from_f64(value)
+    }
+
+    fn parse(&self, text: &str) -> Option<T> {
+        text.trim().parse::<T>().ok().filter(|value| {
+            self.range.start() <= value && value <= self.range.end()
+        })
+    }
+
+    fn can_increment(&self) -> bool {
+        self.value < *self.range.end()
+    }
+
+    fn can_decrement(&self) -> bool {
+        self.value > *self.range.start()
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for NumberInput<'a, T, Message, Renderer>
+where
+    T: Number,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Widget::<String, Renderer>::width(&self.text_input)
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = Widget::<String, Renderer>::layout(
+            &self.text_input,
+            renderer,
+            limits,
+        );
+        let text = node.children()[0].bounds();
+
+        let spinner_size = text.height;
+        let spacing = (spinner_size / 4.0).round();
+        let right = text.x + text.width;
+
+        let mut increment =
+            layout::Node::new(Size::new(spinner_size, spinner_size));
+        increment.move_to(Point::new(right - spinner_size, text.y));
+
+        let mut decrement =
+            layout::Node::new(Size::new(spinner_size, spinner_size));
+        decrement
+            .move_to(Point::new(right - spinner_size * 2.0 - spacing, text.y));
+
+        // The text input expects the bounds of its text as the first child
+        let mut text_node = layout::Node::new(Size::new(
+            (text.width - (spinner_size + spacing) * 2.0).max(0.0),
+            text.height,
+        ));
+        text_node.move_to(Point::new(text.x, text.y));
+
+        layout::Node::with_children(
+            node.size(),
+            vec![text_node, increment, decrement],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let steps = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let mut spinners = layout.children().skip(1);
+                let increment = spinners.next().unwrap().bounds();
+                let decrement = spinners.next().unwrap().bounds();
+
+                if increment.contains(cursor_position) {
+                    Some(1.0)
+                } else if decrement.contains(cursor_position) {
+                    Some(-1.0)
+                } else {
+                    None
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.text_input.state().is_focused() => match key_code {
+                keyboard::KeyCode::Up => Some(1.0),
+                keyboard::KeyCode::Down => Some(-1.0),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Some(steps) = steps {
+            let can_step = if steps > 0.0 {
+                self.can_increment()
+            } else {
+                self.can_decrement()
+            };
+
+            if let Some(value) = self.stepped(steps).filter(|_| can_step) {
+                // Show the stepped value instead of the typed text
+                *self.text = None;
+
+                messages.push((self.on_change)(value));
+            }
+
+            return event::Status::Captured;
+        }
+
+        let mut edits = Vec::new();
+
+        let status = self.text_input.on_event(
+            event,
+            layout,
+            cursor_position,
+            &mut edits,
+            renderer,
+            clipboard,
+        );
+
+        for text in edits {
+            if let Some(value) = self.parse(&text) {
+                messages.push((self.on_change)(value));
+            }
+
+            *self.text = Some(text);
+        }
+
+        if !self.text_input.state().is_focused() {
+            *self.text = None;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let input =
+            self.text_input
+                .draw(renderer, layout, cursor_position, None);
+
+        let mut spinners = layout.children().skip(1);
+        let increment = spinners.next().unwrap().bounds();
+        let decrement = spinners.next().unwrap().bounds();
+
+        self::Renderer::draw(
+            renderer,
+            increment,
+            decrement,
+            cursor_position,
+            self.can_increment(),
+            self.can_decrement(),
+            input,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        Widget::<String, Renderer>::hash_layout(&self.text_input, state);
+    }
+
+    fn operate(
+        &mut self,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.text_input.operate(operation);
+    }
+}
+
+/// The renderer of a [`NumberInput`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`NumberInput`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text_input::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the spinners of a [`NumberInput`] on top of its field.
+    ///
+    /// It receives:
+    /// - the bounds of the increment spinner
+    /// - the bounds of the decrement spinner
+    /// - the cursor position
+    /// - whether the value can be incremented or not
+    /// - whether the value can be decremented or not
+    /// - the output of the [`TextInput`] of the field
+    /// - the style of the spinners
+    fn draw(
+        &mut self,
+        increment_bounds: Rectangle,
+        decrement_bounds: Rectangle,
+        cursor_position: Point,
+        can_increment: bool,
+        can_decrement: bool,
+        input: Self::Output,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Number,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(number_input)
+    }
+}
+
+/// A primitive number that can be entered in a [`NumberInput`].
+///
+/// It is implemented for every primitive integer and floating point type.
+pub trait Number:
+    Copy
+    + PartialOrd
+    + ToString
+    + std::str::FromStr
+    + num_traits::ToPrimitive
+    + num_traits::FromPrimitive
+{
+    /// The default step of a [`NumberInput`].
+    const ONE: Self;
+}
+
+macro_rules! number {
+    ($one:expr; $($type:ty),*) => {
+        $(
+            impl Number for $type {
+                const ONE: Self = $one;
+            }
+        )*
+    };
+}
+
+number!(1; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+number!(1.0; f32, f64);
+
+/// The state of a [`NumberInput`].
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    text_input: text_input::State,
+    text: Option<String>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`NumberInput`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`State`], representing a focused [`NumberInput`].
+    pub fn focused() -> Self {
+        Self {
+            text_input: text_input::State::focused(),
+            text: None,
+        }
+    }
+
+    /// Returns whether the [`NumberInput`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.text_input.is_focused()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+
+    fn input<T: Number>(
+        state: &mut State,
+        value: T,
+        range: RangeInclusive<T>,
+    ) -> NumberInput<'_, T, (), Null> {
+        NumberInput::new(state, value, range, |_| ())
+    }
+
+    #[test]
+    fn steps_by_one_by_default() {
+        let mut state = State::new();
+
+        assert_eq!(input(&mut state, -3i8, -10..=10).stepped(1.0), Some(-2));
+        assert_eq!(
+            input(&mut state, 0.5f32, 0.0..=2.0).stepped(1.0),
+            Some(1.5)
+        );
+    }
+
+    #[test]
+    fn steps_negative_integers_within_range() {
+        let mut state = State::new();
+        let input = input(&mut state, -100i8, -128..=127).step(10);
+
+        assert_eq!(input.stepped(-1.0), Some(-110));
+        assert_eq!(input.stepped(-5.0), Some(-128));
+        assert_eq!(input.stepped(30.0), Some(127));
+    }
+
+    #[test]
+    fn clamps_value_to_range() {
+        let mut state = State::new();
+
+        let below = input(&mut state, -5i32, 0..=10);
+        assert_eq!(below.value, 0);
+        assert!(!below.can_decrement());
+        assert!(below.can_increment());
+
+        let above = input(&mut state, 2.5f64, 0.0..=1.0);
+        assert_eq!(above.value, 1.0);
+        assert!(!above.can_increment());
+        assert!(above.can_decrement());
+    }
+
+    #[test]
+    fn parses_numbers_within_range() {
+        let mut state = State::new();
+
+        let integers = input(&mut state, 0i8, -50..=50);
+        assert_eq!(integers.parse(" -42 "), Some(-42));
+        assert_eq!(integers.parse("51"), None);
+        assert_eq!(integers.parse("300"), None);
+        assert_eq!(integers.parse("4.2"), None);
+        assert_eq!(integers.parse("forty"), None);
+        assert_eq!(integers.parse(""), None);
+
+        let floats = input(&mut state, 0.0f32, -1.0..=1.0);
+        assert_eq!(floats.parse("0.25"), Some(0.25));
+        assert_eq!(floats.parse("-1"), Some(-1.0));
+        assert_eq!(floats.parse("1.5"), None);
+        assert_eq!(floats.parse("NaN"), None);
+    }
+
+    #[test]
+    fn steps_wide_integers_within_range() {
+        let mut state = State::new();
+
+        let input: NumberInput<'_, u64, (), Null> =
+            NumberInput::new(&mut state, 9_000, 0..=10_000, |_| ()).step(500);
+
+        assert_eq!(input.stepped(1.0), Some(9_500));
+        assert_eq!(input.stepped(3.0), Some(10_000));
+        assert_eq!(input.stepped(-20.0), Some(0));
+    }
+}
@@ -0,0 +1,378 @@
+//! Query or update the internal state of widgets.
+//!
+//! An [`Operation`] traverses the widget tree of a [`UserInterface`], usually
+//! looking for a widget with a specific [`Id`]. Run one by returning it in a
+//! [`Command`] from the update logic of your application.
+//!
+//! [`UserInterface`]: crate::UserInterface
+//! [`Command`]: crate::Command
+pub mod focusable;
+pub mod scrollable;
+pub mod text_input;
+
+pub use focusable::Focusable;
+pub use scrollable::Scrollable;
+pub use text_input::TextInput;
+
+use crate::widget::Id;
+
+use std::fmt;
+use std::rc::Rc;
+
+/// A piece of logic that traverses the widget tree of a user interface to
+/// query or update the state of some widgets.
+pub trait Operation<T> {
+    /// Operates on a widget that contains other widgets.
+    ///
+    /// Calling `operate_on_children` keeps traversing the widget tree through
+    /// the children of the widget.
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    );
+
+    /// Operates on a widget that can be focused.
+    fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
+
+    /// Operates on a widget that can be scrolled.
+    fn scrollable(&mut self, _state: &mut dyn Scrollable, _id: Option<&Id>) {}
+
+    /// Operates on a widget that has some text input.
+    fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
+
+    /// Finishes the [`Operation`] once the whole widget tree has been
+    /// traversed, returning its [`Outcome`].
+    fn finish(&self) -> Outcome<T> {
+        Outcome::None
+    }
+}
+
+/// The result of an [`Operation`].
+pub enum Outcome<T> {
+    /// The [`Operation`] produced no result.
+    None,
+
+    /// The [`Operation`] produced some result.
+    Some(T),
+
+    /// The [`Operation`] needs another traversal of the widget tree, running
+    /// the given [`Operation`].
+    Chain(Box<dyn Operation<T>>),
+}
+
+impl<T> fmt::Debug for Outcome<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "Outcome::None"),
+            Self::Some(output) => write!(f, "Outcome::Some({:?})", output),
+            Self::Chain(_) => write!(f, "Outcome::Chain(...)"),
+        }
+    }
+}
+
+/// Maps the result of an [`Operation`] with the given function.
+pub fn map<A, B>(
+    operation: Box<dyn Operation<A>>,
+    f: impl Fn(A) -> B + 'static,
+) -> impl Operation<B>
+where
+    A: 'static,
+    B: 'static,
+{
+    Map {
+        operation,
+        f: Rc::new(f),
+    }
+}
+
+struct Map<A, B> {
+    operation: Box<dyn Operation<A>>,
+    f: Rc<dyn Fn(A) -> B>,
+}
+
+impl<A, B> Operation<B> for Map<A, B>
+where
+    A: 'static,
+    B: 'static,
+{
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        self.operation.container(id, &mut |operation| {
+            operate_on_children(&mut Inner { operation });
+        });
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        self.operation.focusable(state, id);
+    }
+
+    fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+        self.operation.scrollable(state, id);
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.operation.text_input(state, id);
+    }
+
+    fn finish(&self) -> Outcome<B> {
+        match self.operation.finish() {
+            Outcome::None => Outcome::None,
+            Outcome::Some(output) => Outcome::Some((self.f)(output)),
+            Outcome::Chain(next) => Outcome::Chain(Box::new(Map {
+                operation: next,
+                f: self.f.clone(),
+            })),
+        }
+    }
+}
+
+/// An [`Operation`] on some widgets, seen with a different result type.
+///
+/// The result of the wrapped [`Operation`] is only produced once the widget
+/// tree has been traversed, so the widgets in between can ignore it.
+pub(crate) struct Inner<'a, A> {
+    pub(crate) operation: &'a mut dyn Operation<A>,
+}
+
+impl<'a, A, B> Operation<B> for Inner<'a, A> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        self.operation.container(id, &mut |operation| {
+            operate_on_children(&mut Inner { operation });
+        });
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        self.operation.focusable(state, id);
+    }
+
+    fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+        self.operation.scrollable(state, id);
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.operation.text_input(state, id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+    use crate::widget::operation::focusable::Count;
+    use crate::{
+        text_input, Cache, Column, Element, Length, Rectangle, Scrollable,
+        Size, TextInput, UserInterface,
+    };
+
+    /// Runs the [`Operation`] on the given widgets, following its chain
+    /// like the runtime does.
+    fn run<'a, T: 'static>(
+        root: impl Into<Element<'a, T, Null>>,
+        mut operation: Box<dyn Operation<T>>,
+    ) -> Option<T> {
+        let mut renderer = Null::new();
+        let mut user_interface = UserInterface::build(
+            root,
+            Size::new(200.0, 100.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        loop {
+            user_interface.operate(operation.as_mut());
+
+            match operation.finish() {
+                Outcome::None => return None,
+                Outcome::Some(output) => return Some(output),
+                Outcome::Chain(next) => operation = next,
+            }
+        }
+    }
+
+    fn inputs<T: 'static + Clone + Default>(
+        states: &mut [text_input::State],
+    ) -> Column<'_, T, Null> {
+        states.iter_mut().enumerate().fold(
+            Column::new(),
+            |column, (i, state)| {
+                column.push(
+                    TextInput::new(state, "", "", |_| T::default())
+                        .id(Id::new(format!("input-{}", i))),
+                )
+            },
+        )
+    }
+
+    fn focused(states: &[text_input::State]) -> Vec<bool> {
+        states.iter().map(text_input::State::is_focused).collect()
+    }
+
+    #[test]
+    fn focus_unfocuses_every_other_widget() {
+        let mut states =
+            [text_input::State::focused(), text_input::State::new()];
+
+        let _ = run(
+            inputs::<()>(&mut states),
+            Box::new(focusable::focus(Id::new("input-1"))),
+        );
+
+        assert_eq!(focused(&states), [false, true]);
+    }
+
+    #[test]
+    fn focus_next_wraps_around() {
+        let mut states = [
+            text_input::State::new(),
+            text_input::State::new(),
+            text_input::State::new(),
+        ];
+
+        let expected = [
+            [true, false, false],
+            [false, true, false],
+            [false, false, true],
+            [true, false, false],
+        ];
+
+        for expected in expected.iter() {
+            let _ = run(
+                inputs::<()>(&mut states),
+                Box::new(focusable::focus_next()),
+            );
+
+            assert_eq!(focused(&states), expected);
+        }
+    }
+
+    #[test]
+    fn focus_previous_wraps_around() {
+        let mut states = [
+            text_input::State::new(),
+            text_input::State::new(),
+            text_input::State::new(),
+        ];
+
+        let expected = [
+            [false, false, true],
+            [false, true, false],
+            [true, false, false],
+            [false, false, true],
+        ];
+
+        for expected in expected.iter() {
+            let _ = run(
+                inputs::<()>(&mut states),
+                Box::new(focusable::focus_previous()),
+            );
+
+            assert_eq!(focused(&states), expected);
+        }
+    }
+
+    #[test]
+    fn chain_runs_on_another_traversal() {
+        let mut states =
+            [text_input::State::focused(), text_input::State::new()];
+        let mut renderer = Null::new();
+
+        let mut operation: Box<dyn Operation<()>> =
+            Box::new(focusable::focus_next());
+
+        {
+            let mut user_interface = UserInterface::build(
+                inputs::<()>(&mut states),
+                Size::new(200.0, 100.0),
+                Cache::new(),
+                &mut renderer,
+            );
+
+            user_interface.operate(operation.as_mut());
+
+            operation = match operation.finish() {
+                Outcome::Chain(next) => next,
+                outcome => panic!("Expected a chain, got {:?}", outcome),
+            };
+        }
+
+        // Counting the focusable widgets does not change their focus
+        assert_eq!(focused(&states), [true, false]);
+
+        assert!(run(inputs::<()>(&mut states), operation).is_none());
+        assert_eq!(focused(&states), [false, true]);
+    }
+
+    #[test]
+    fn map_transforms_result() {
+        let mut states =
+            [text_input::State::new(), text_input::State::focused()];
+
+        let count = map(
+            Box::new(focusable::count(|count: Count| count)),
+            |count: Count| (count.focused, count.total),
+        );
+
+        assert_eq!(
+            run(inputs(&mut states), Box::new(count)),
+            Some((Some(1), 2))
+        );
+    }
+
+    #[test]
+    fn map_follows_chain() {
+        let mut states =
+            [text_input::State::new(), text_input::State::focused()];
+
+        let next = map(Box::new(focusable::focus_next::<()>()), |_| 42);
+
+        assert_eq!(run(inputs::<u32>(&mut states), Box::new(next)), None);
+        assert_eq!(focused(&states), [true, false]);
+    }
+
+    #[test]
+    fn snap_to_scrolls_target_only() {
+        let mut target = crate::scrollable::State::new();
+        let mut other = crate::scrollable::State::new();
+
+        let list = |state, id| {
+            Scrollable::new(state)
+                .id(Id::new(id))
+                .height(Length::Units(50))
+                .push(Column::new().height(Length::Units(250)))
+        };
+
+        let _ = run(
+            Column::<(), Null>::new()
+                .push(list(&mut target, "target"))
+                .push(list(&mut other, "other")),
+            Box::new(scrollable::snap_to(Id::new("target"), 0.5)),
+        );
+
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 200.0,
+            height: 50.0,
+        };
+
+        let content_bounds = Rectangle {
+            height: 250.0,
+            ..bounds
+        };
+
+        assert_eq!(target.offset(bounds, content_bounds), 100);
+        assert_eq!(other.offset(bounds, content_bounds), 0);
+    }
+}
@@ -0,0 +1,179 @@
+//! Operate on widgets that can be focused.
+use crate::widget::operation::{Operation, Outcome};
+use crate::widget::Id;
+
+/// The internal state of a widget that can be focused.
+pub trait Focusable {
+    /// Returns whether the widget is focused or not.
+    fn is_focused(&self) -> bool;
+
+    /// Focuses the widget.
+    fn focus(&mut self);
+
+    /// Unfocuses the widget.
+    fn unfocus(&mut self);
+}
+
+/// A summary of the focusable widgets of a user interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Count {
+    /// The index of the focused widget, if any.
+    pub focused: Option<usize>,
+
+    /// The total amount of focusable widgets.
+    pub total: usize,
+}
+
+/// Produces an [`Operation`] that focuses the widget with the given [`Id`],
+/// unfocusing any other.
+pub fn focus<T>(target: Id) -> impl Operation<T> {
+    struct Focus {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for Focus {
+        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+            if id == Some(&self.target) {
+                state.focus();
+            } else {
+                state.unfocus();
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    Focus { target }
+}
+
+/// Produces an [`Operation`] that unfocuses the focused widget, if any.
+pub fn unfocus<T>() -> impl Operation<T> {
+    struct Unfocus;
+
+    impl<T> Operation<T> for Unfocus {
+        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+            state.unfocus();
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    Unfocus
+}
+
+/// Produces an [`Operation`] that counts the focusable widgets and produces
+/// the [`Count`] with the given function.
+pub fn count<T, F>(f: F) -> impl Operation<T>
+where
+    F: Fn(Count) -> T,
+{
+    Counting {
+        count: Count::default(),
+        finish: move |count| Outcome::Some(f(count)),
+    }
+}
+
+/// Produces an [`Operation`] that moves the focus to the next focusable
+/// widget, wrapping around after the last one.
+pub fn focus_next<T>() -> impl Operation<T>
+where
+    T: 'static,
+{
+    Counting {
+        count: Count::default(),
+        finish: |count: Count| {
+            let target = match count.focused {
+                Some(focused) => (focused + 1) % count.total.max(1),
+                None => 0,
+            };
+
+            Outcome::Chain(Box::new(FocusIndex { target, current: 0 }))
+        },
+    }
+}
+
+/// Produces an [`Operation`] that moves the focus to the previous focusable
+/// widget, wrapping around before the first one.
+pub fn focus_previous<T>() -> impl Operation<T>
+where
+    T: 'static,
+{
+    Counting {
+        count: Count::default(),
+        finish: |count: Count| {
+            let target = match count.focused {
+                Some(focused) => (focused + count.total - 1) % count.total,
+                None => count.total.saturating_sub(1),
+            };
+
+            Outcome::Chain(Box::new(FocusIndex { target, current: 0 }))
+        },
+    }
+}
+
+struct Counting<F> {
+    count: Count,
+    finish: F,
+}
+
+impl<T, F> Operation<T> for Counting<F>
+where
+    F: Fn(Count) -> Outcome<T>,
+{
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if state.is_focused() {
+            self.count.focused = Some(self.count.total);
+        }
+
+        self.count.total += 1;
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self)
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        (self.finish)(self.count)
+    }
+}
+
+struct FocusIndex {
+    target: usize,
+    current: usize,
+}
+
+impl<T> Operation<T> for FocusIndex {
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if self.current == self.target {
+            state.focus();
+        } else {
+            state.unfocus();
+        }
+
+        self.current += 1;
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self)
+    }
+}
@@ -34,6 +34,7 @@ use crate::mouse;
 use crate::overlay;
 use crate::row;
 use crate::text;
+use crate::widget::Operation;
 use crate::{
     Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Vector,
     Widget,
@@ -535,6 +536,18 @@ where
             .filter_map(|((_, pane), layout)| pane.overlay(layout))
             .next()
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.elements.iter_mut().zip(layout.children()).for_each(
+                |((_, pane), layout)| pane.operate(layout, operation),
+            );
+        });
+    }
 }
 
 /// The renderer of a [`PaneGrid`].
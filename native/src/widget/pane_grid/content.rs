@@ -3,6 +3,7 @@ use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
 use crate::pane_grid::{self, TitleBar};
+use crate::widget::Operation;
 use crate::{Clipboard, Element, Hasher, Layout, Point, Size};
 
 /// The content of a [`Pane`].
@@ -200,6 +201,28 @@ where
 
         self.body.overlay(body_layout)
     }
+
+    pub(crate) fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let body_layout = if self.title_bar.is_some() {
+            let mut children = layout.children();
+
+            // Operations only reach the pane body, like overlays.
+            let _title_bar_layout = children.next();
+
+            match children.next() {
+                Some(body_layout) => body_layout,
+                None => return,
+            }
+        } else {
+            layout
+        };
+
+        self.body.operate(body_layout, operation);
+    }
 }
 
 impl<'a, T, Message, Renderer> From<T> for Content<'a, Message, Renderer>
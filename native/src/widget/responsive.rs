@@ -0,0 +1,263 @@
+//! Build parts of your user interface based on the space they are given.
+//!
+//! A [`Responsive`] widget has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::overlay;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::cell::Cell;
+use std::hash::Hash;
+
+/// A widget that produces its contents from the [`Size`] it is allocated.
+///
+/// The view logic of a [`Responsive`] widget runs once, when the widget is
+/// created, with the [`Size`] allocated to it the last time the user
+/// interface was laid out. Therefore, its contents can borrow the local state
+/// of stateful widgets, like any other part of your view.
+///
+/// If the allocated [`Size`] changes, the contents are laid out in the new
+/// [`Size`] and they are built again for it the next time your view logic
+/// runs. Initially, the last known [`Size`] is [`Size::ZERO`].
+///
+/// # Example
+/// ```
+/// # use iced_native::{button, responsive, renderer::Null, Responsive};
+/// #
+/// # pub type Button<'a, Message> = iced_native::Button<'a, Message, Null>;
+/// # pub type Text = iced_native::Text<Null>;
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     Play,
+/// }
+///
+/// let mut state = responsive::State::new();
+/// let mut play_button = button::State::new();
+/// let title = String::from("Big Buck Bunny");
+///
+/// let responsive: Responsive<'_, Message, Null> =
+///     Responsive::new(&mut state, |size| {
+///         let label = if size.width < 400.0 {
+///             Text::new(&title[..10])
+///         } else {
+///             Text::new(&title).size(40)
+///         };
+///
+///         Button::new(&mut play_button, label)
+///             .on_press(Message::Play)
+///             .into()
+///     });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Responsive<'a, Message, Renderer> {
+    state: &'a State,
+    content: Element<'a, Message, Renderer>,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Message, Renderer> Responsive<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`Responsive`] widget with the given [`State`] and view
+    /// logic.
+    ///
+    /// The `view` closure receives the [`Size`] allocated to the widget the
+    /// last time it was laid out.
+    pub fn new(
+        state: &'a mut State,
+        view: impl FnOnce(Size) -> Element<'a, Message, Renderer>,
+    ) -> Self {
+        let content = view(state.size());
+
+        Responsive {
+            state,
+            content,
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// Sets the width of the [`Responsive`] widget.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Responsive`] widget.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Responsive<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(Size::ZERO);
+
+        self.state.size.set(size);
+
+        let node = self
+            .content
+            .layout(renderer, &layout::Limits::new(Size::ZERO, size));
+
+        layout::Node::with_children(size, vec![node])
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.content.draw(
+            renderer,
+            defaults,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.content.hash_layout(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.content.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .operate(layout.children().next().unwrap(), operation);
+    }
+}
+
+/// The state of a [`Responsive`] widget.
+///
+/// It remembers the [`Size`] allocated to the widget the last time it was
+/// laid out.
+#[derive(Debug, Clone)]
+pub struct State {
+    size: Cell<Size>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self {
+            size: Cell::new(Size::ZERO),
+        }
+    }
+
+    /// Returns the [`Size`] allocated to the [`Responsive`] widget the last
+    /// time it was laid out.
+    pub fn size(&self) -> Size {
+        self.size.get()
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> From<Responsive<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        responsive: Responsive<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(responsive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{button, Button, Text};
+
+    #[test]
+    fn view_receives_last_allocated_size() {
+        let mut state = State::new();
+        let mut button = button::State::new();
+        let renderer = Null::new();
+        let limits = layout::Limits::new(Size::ZERO, Size::new(300.0, 200.0));
+
+        for expected in [Size::ZERO, Size::new(300.0, 200.0)].iter() {
+            let responsive: Responsive<'_, (), Null> =
+                Responsive::new(&mut state, |size| {
+                    assert_eq!(size, *expected);
+
+                    Button::new(&mut button, Text::new("Play")).into()
+                });
+
+            let node = responsive.layout(&renderer, &limits);
+
+            assert_eq!(node.size(), Size::new(300.0, 200.0));
+        }
+
+        assert_eq!(state.size(), Size::new(300.0, 200.0));
+    }
+}
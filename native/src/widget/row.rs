@@ -2,6 +2,7 @@
 use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
+use crate::widget::Operation;
 use crate::{
     Align, Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Widget,
 };
@@ -204,6 +205,19 @@ where
             .filter_map(|(child, layout)| child.widget.overlay(layout))
             .next()
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(layout.children())
+                .for_each(|(child, layout)| child.operate(layout, operation));
+        });
+    }
 }
 
 /// The renderer of a [`Row`].
@@ -4,8 +4,10 @@ use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
+use crate::widget::operation::{self, Operation};
+use crate::widget::Id;
 use crate::{
-    Align, Clipboard, Column, Element, Hasher, Layout, Length, Point,
+    Align, Clipboard, Column, Command, Element, Hasher, Layout, Length, Point,
     Rectangle, Size, Vector, Widget,
 };
 
@@ -16,6 +18,7 @@ use std::{f32, hash::Hash, u32};
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
+    id: Option<Id>,
     height: Length,
     max_height: u32,
     scrollbar_width: u16,
@@ -30,6 +33,7 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
     pub fn new(state: &'a mut State) -> Self {
         Scrollable {
             state,
+            id: None,
             height: Length::Shrink,
             max_height: u32::MAX,
             scrollbar_width: 10,
@@ -40,6 +44,12 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         }
     }
 
+    /// Sets the [`Id`] of the [`Scrollable`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the vertical spacing _between_ elements.
     ///
     /// Custom margins per element do not exist in Iced. You should use this
@@ -379,6 +389,57 @@ where
                 overlay.translate(Vector::new(0.0, -(offset as f32)))
             })
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let content_layout = layout.children().next().unwrap();
+
+        operation.scrollable(
+            &mut Snapping {
+                state: self.state,
+                bounds: layout.bounds(),
+                content_bounds: content_layout.bounds(),
+            },
+            self.id.as_ref(),
+        );
+
+        let content = &mut self.content;
+
+        operation.container(self.id.as_ref(), &mut |operation| {
+            Widget::<Message, Renderer>::operate(
+                content,
+                content_layout,
+                operation,
+            );
+        });
+    }
+}
+
+/// The [`State`] of a [`Scrollable`] together with its current bounds, which
+/// are needed to snap it.
+struct Snapping<'a> {
+    state: &'a mut State,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+}
+
+impl<'a> operation::Scrollable for Snapping<'a> {
+    fn snap_to(&mut self, percentage: f32) {
+        self.state
+            .scroll_to(percentage, self.bounds, self.content_bounds);
+    }
+}
+
+/// Produces a [`Command`] that snaps the [`Scrollable`] with the given [`Id`]
+/// to the given percentage of its content.
+///
+/// `0` represents scrollbar at the top, while `1` represents scrollbar at
+/// the bottom.
+pub fn snap_to<Message: 'static>(id: Id, percentage: f32) -> Command<Message> {
+    Command::widget(operation::scrollable::snap_to(id, percentage))
 }
 
 /// The local state of a [`Scrollable`].
@@ -0,0 +1,539 @@
+//! Filter large lists with a text field that debounces its searches.
+//!
+//! A [`SearchInput`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::subscription::{self, Subscription};
+use crate::text_input::{self, TextInput};
+use crate::widget::{Id, Operation};
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use iced_futures::futures::channel::mpsc;
+use std::hash::Hash;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+/// A [`TextInput`] with a search icon and a button to clear it.
+///
+/// Every change is produced right away, so the value of the [`SearchInput`]
+/// stays up to date. The searches themselves are produced by the
+/// [`State::searches`] subscription, once the user stops typing, or by the
+/// [`State::queries`] subscription without any delay.
+///
+/// # Example
+/// ```
+/// # use iced_native::{search_input, renderer::Null};
+/// #
+/// # pub type SearchInput<'a, Message> =
+/// #     iced_native::SearchInput<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QueryChanged(String),
+///     Search(String),
+/// }
+///
+/// let mut state = search_input::State::new();
+/// let query = "nina";
+///
+/// let input = SearchInput::new(
+///     &mut state,
+///     "Search the playlist...",
+///     query,
+///     Message::QueryChanged,
+/// )
+/// .padding(10);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SearchInput<'a, Message, Renderer: self::Renderer> {
+    text_input: TextInput<'a, Message, Renderer>,
+    queries: Arc<Mutex<Queries>>,
+    value: String,
+    size: Option<u16>,
+    on_change: Rc<dyn Fn(String) -> Message>,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> SearchInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`SearchInput`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - a placeholder
+    /// - the current value
+    /// - a function that produces a message when the [`SearchInput`] changes
+    pub fn new<F>(
+        state: &'a mut State,
+        placeholder: &str,
+        value: &str,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        let on_change = Rc::new(on_change);
+
+        let text_input =
+            TextInput::new(&mut state.text_input, placeholder, value, {
+                let queries = Arc::clone(&state.queries);
+                let on_change = Rc::clone(&on_change);
+
+                move |value| {
+                    queries.lock().expect("Lock search queries").send(&value);
+
+                    on_change(value)
+                }
+            });
+
+        SearchInput {
+            text_input,
+            queries: Arc::clone(&state.queries),
+            value: String::from(value),
+            size: None,
+            on_change,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`SearchInput`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`SearchInput`].
+    ///
+    /// [`Font`]: crate::widget::text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the width of the [`SearchInput`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the maximum width of the [`SearchInput`].
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.text_input = self.text_input.max_width(max_width);
+        self
+    }
+
+    /// Sets the padding of the [`SearchInput`].
+    pub fn padding(mut self, units: u16) -> Self {
+        self.text_input = self.text_input.padding(units);
+        self
+    }
+
+    /// Sets the text size of the [`SearchInput`].
+    ///
+    /// The search icon and the clear button are sized accordingly.
+    pub fn size(mut self, size: u16) -> Self {
+        self.text_input = self.text_input.size(size);
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`SearchInput`] is
+    /// focused and the enter key is pressed.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.text_input = self.text_input.on_submit(message);
+        self
+    }
+
+    /// Sets the style of the field of the [`SearchInput`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as text_input::Renderer>::Style>,
+    ) -> Self {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style of the search icon and the clear button of the
+    /// [`SearchInput`].
+    pub fn icon_style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for SearchInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Widget::<Message, Renderer>::width(&self.text_input)
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = self.text_input.layout(renderer, limits);
+        let text = node.children()[0].bounds();
+
+        let icon_size = text.height;
+        let spacing = (icon_size / 2.0).round();
+
+        let mut icon = layout::Node::new(Size::new(icon_size, icon_size));
+        icon.move_to(Point::new(text.x, text.y));
+
+        let mut clear = layout::Node::new(Size::new(icon_size, icon_size));
+        clear.move_to(Point::new(text.x + text.width - icon_size, text.y));
+
+        // The text input expects the bounds of its text as the first child
+        let mut text_node = layout::Node::new(Size::new(
+            (text.width - (icon_size + spacing) * 2.0).max(0.0),
+            text.height,
+        ));
+        text_node.move_to(Point::new(text.x + icon_size + spacing, text.y));
+
+        layout::Node::with_children(node.size(), vec![text_node, icon, clear])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            let clear = layout.children().nth(2).unwrap().bounds();
+
+            if !self.value.is_empty() && clear.contains(cursor_position) {
+                self.queries.lock().expect("Lock search queries").send("");
+                messages.push((self.on_change)(String::new()));
+
+                return event::Status::Captured;
+            }
+        }
+
+        self.text_input.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let input =
+            self.text_input
+                .draw(renderer, layout, cursor_position, None);
+
+        let mut children = layout.children().skip(1);
+        let icon = children.next().unwrap().bounds();
+        let clear = children.next().unwrap().bounds();
+
+        self::Renderer::draw(
+            renderer,
+            icon,
+            if self.value.is_empty() {
+                None
+            } else {
+                Some(clear)
+            },
+            cursor_position,
+            input,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.text_input.hash_layout(state);
+        self.size.hash(state);
+    }
+
+    fn operate(
+        &mut self,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.text_input.operate(operation);
+    }
+}
+
+/// The renderer of a [`SearchInput`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`SearchInput`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text_input::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the search icon and the clear button of a [`SearchInput`] on top
+    /// of its field.
+    ///
+    /// It receives:
+    /// - the bounds of the search icon
+    /// - the bounds of the clear button, if the [`SearchInput`] is not empty
+    /// - the cursor position
+    /// - the output of the [`TextInput`] of the field
+    /// - the style of the search icon and the clear button
+    fn draw(
+        &mut self,
+        icon_bounds: Rectangle,
+        clear_bounds: Option<Rectangle>,
+        cursor_position: Point,
+        input: Self::Output,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<SearchInput<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        search_input: SearchInput<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(search_input)
+    }
+}
+
+/// The state of a [`SearchInput`].
+#[derive(Debug, Default)]
+pub struct State {
+    text_input: text_input::State,
+    queries: Arc<Mutex<Queries>>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`SearchInput`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`State`], representing a focused [`SearchInput`].
+    pub fn focused() -> Self {
+        Self {
+            text_input: text_input::State::focused(),
+            queries: Default::default(),
+        }
+    }
+
+    /// Returns whether the [`SearchInput`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.text_input.is_focused()
+    }
+
+    /// Returns a [`Subscription`] that produces every value of the
+    /// [`SearchInput`] as the user changes it.
+    ///
+    /// Clearing the [`SearchInput`] produces an empty query.
+    pub fn queries(&self) -> Subscription<String> {
+        Subscription::from_recipe(Changes {
+            queries: Arc::clone(&self.queries),
+        })
+    }
+
+    /// Returns a [`Subscription`] that produces the value of the
+    /// [`SearchInput`] once it has not changed for the given delay.
+    ///
+    /// It debounces the [`queries`] of the [`SearchInput`]. Clearing the
+    /// [`SearchInput`] produces an empty search.
+    ///
+    /// # Example
+    /// ```
+    /// # use iced_native::search_input;
+    /// #
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     Search(String),
+    /// }
+    ///
+    /// let state = search_input::State::new();
+    ///
+    /// let searches = state
+    ///     .searches(std::time::Duration::from_millis(300))
+    ///     .map(Message::Search);
+    /// ```
+    ///
+    /// [`queries`]: Self::queries
+    #[cfg(all(
+        any(feature = "tokio", feature = "tokio_old", feature = "async-std"),
+        not(target_arch = "wasm32")
+    ))]
+    pub fn searches(&self, delay: std::time::Duration) -> Subscription<String> {
+        self.queries().debounce(delay)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Queries {
+    next_id: u64,
+    subscribers: Vec<(u64, mpsc::UnboundedSender<String>)>,
+}
+
+impl Queries {
+    fn send(&mut self, query: &str) {
+        self.subscribers.retain(|(_, sender)| {
+            sender.unbounded_send(String::from(query)).is_ok()
+        });
+    }
+
+    fn subscribe(&mut self, sender: mpsc::UnboundedSender<String>) -> u64 {
+        let id = self.next_id;
+
+        self.next_id += 1;
+        self.subscribers.push((id, sender));
+
+        id
+    }
+
+    fn unsubscribe(&mut self, id: u64) {
+        self.subscribers.retain(|(subscriber, _)| *subscriber != id);
+    }
+}
+
+struct Changes {
+    queries: Arc<Mutex<Queries>>,
+}
+
+impl<H, E> subscription::Recipe<H, E> for Changes
+where
+    H: std::hash::Hasher,
+{
+    type Output = String;
+
+    fn hash(&self, state: &mut H) {
+        std::any::TypeId::of::<Self>().hash(state);
+        (Arc::as_ptr(&self.queries) as usize).hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: iced_futures::BoxStream<E>,
+    ) -> iced_futures::BoxStream<String> {
+        use iced_futures::futures::StreamExt;
+
+        let (sender, queries) = mpsc::unbounded();
+
+        let id = self
+            .queries
+            .lock()
+            .expect("Lock search queries")
+            .subscribe(sender);
+
+        Subscriber {
+            hub: self.queries,
+            id,
+            queries,
+        }
+        .boxed()
+    }
+}
+
+/// The stream of a [`Changes`] subscription, which unsubscribes from the
+/// queries of its [`SearchInput`] when dropped.
+struct Subscriber {
+    hub: Arc<Mutex<Queries>>,
+    id: u64,
+    queries: mpsc::UnboundedReceiver<String>,
+}
+
+impl iced_futures::futures::Stream for Subscriber {
+    type Item = String;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        context: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<String>> {
+        std::pin::Pin::new(&mut self.queries).poll_next(context)
+    }
+}
+
+impl Drop for Subscriber {
+    fn drop(&mut self) {
+        if let Ok(mut queries) = self.hub.lock() {
+            queries.unsubscribe(self.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_futures::futures::executor::block_on;
+    use iced_futures::futures::stream::{self, StreamExt};
+
+    fn queries(state: &State) -> iced_futures::BoxStream<String> {
+        state
+            .queries()
+            .recipes()
+            .remove(0)
+            .stream(stream::empty().boxed())
+    }
+
+    #[test]
+    fn queries_produce_every_query() {
+        let state = State::new();
+        let mut queries = queries(&state);
+
+        for query in &["n", "ni", ""] {
+            state.queries.lock().expect("Lock queries").send(query);
+        }
+
+        let received: Vec<String> = block_on((&mut queries).take(3).collect());
+
+        assert_eq!(received, vec!["n", "ni", ""]);
+    }
+
+    #[test]
+    fn dropping_queries_unsubscribes() {
+        let state = State::new();
+        let first = queries(&state);
+        let second = queries(&state);
+
+        assert_eq!(state.queries.lock().expect("Lock").subscribers.len(), 2);
+
+        drop(first);
+
+        assert_eq!(state.queries.lock().expect("Lock").subscribers.len(), 1);
+
+        drop(second);
+
+        assert!(state.queries.lock().expect("Lock").subscribers.is_empty());
+    }
+}
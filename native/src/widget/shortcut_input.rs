@@ -0,0 +1,348 @@
+//! Let users rebind keyboard shortcuts by pressing them.
+//!
+//! A [`ShortcutInput`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard::{self, Shortcut};
+use crate::layout;
+use crate::mouse;
+use crate::text;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A field that shows a keyboard [`Shortcut`] and captures the next one
+/// pressed after clicking it.
+///
+/// Modifier keys alone are not captured, and pressing `Escape` or clicking
+/// anywhere else cancels the capture.
+///
+/// # Example
+/// ```
+/// # use iced_native::{shortcut_input, renderer::Null};
+/// # use iced_native::keyboard::{KeyCode, Keymap, Shortcut};
+/// #
+/// # pub type ShortcutInput<'a, Message> =
+/// #     iced_native::ShortcutInput<'a, Message, Null>;
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Action {
+///     TogglePlayback,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Rebound(Action, Shortcut),
+/// }
+///
+/// let keymap = Keymap::new()
+///     .with(Action::TogglePlayback, Shortcut::from(KeyCode::Space));
+///
+/// let mut state = shortcut_input::State::new();
+///
+/// let input = ShortcutInput::new(
+///     &mut state,
+///     keymap.shortcut(&Action::TogglePlayback),
+///     |shortcut| Message::Rebound(Action::TogglePlayback, shortcut),
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ShortcutInput<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    shortcut: Option<Shortcut>,
+    on_change: Box<dyn Fn(Shortcut) -> Message>,
+    placeholder: String,
+    prompt: String,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer>
+    ShortcutInput<'a, Message, Renderer>
+{
+    /// Creates a new [`ShortcutInput`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - the current [`Shortcut`], if any
+    /// - a function that produces a message when a new [`Shortcut`] is
+    ///   captured
+    pub fn new<F>(
+        state: &'a mut State,
+        shortcut: Option<Shortcut>,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(Shortcut) -> Message,
+    {
+        ShortcutInput {
+            state,
+            shortcut,
+            on_change: Box::new(on_change),
+            placeholder: String::from("Unassigned"),
+            prompt: String::from("Press a shortcut..."),
+            width: Length::Shrink,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the text shown when the [`ShortcutInput`] has no [`Shortcut`].
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = String::from(placeholder);
+        self
+    }
+
+    /// Sets the text shown while the [`ShortcutInput`] is capturing.
+    pub fn prompt(mut self, prompt: &str) -> Self {
+        self.prompt = String::from(prompt);
+        self
+    }
+
+    /// Sets the width of the [`ShortcutInput`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`ShortcutInput`].
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`ShortcutInput`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`ShortcutInput`].
+    ///
+    /// [`Font`]: crate::widget::text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`ShortcutInput`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn label(&self) -> String {
+        if self.state.is_capturing {
+            self.prompt.clone()
+        } else {
+            self.shortcut
+                .map(|shortcut| shortcut.to_string())
+                .unwrap_or_else(|| self.placeholder.clone())
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ShortcutInput<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let padding = f32::from(self.padding);
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        // Keep the same width while capturing and after
+        let width = [&self.label(), &self.prompt, &self.placeholder]
+            .iter()
+            .map(|label| {
+                renderer
+                    .measure(label, text_size, self.font, Size::INFINITY)
+                    .0
+            })
+            .fold(0.0, f32::max);
+
+        let size = limits.resolve(Size::new(
+            width + padding * 2.0,
+            f32::from(text_size) + padding * 2.0,
+        ));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let is_mouse_over = layout.bounds().contains(cursor_position);
+
+                self.state.is_capturing = is_mouse_over;
+
+                if is_mouse_over {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.state.is_capturing => {
+                if key_code == keyboard::KeyCode::Escape {
+                    self.state.is_capturing = false;
+                } else if !Shortcut::is_modifier(key_code) {
+                    self.state.is_capturing = false;
+
+                    messages.push((self.on_change)(Shortcut::new(
+                        key_code, modifiers,
+                    )));
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(_) if self.state.is_capturing => {
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            &self.label(),
+            self.shortcut.is_some() || self.state.is_capturing,
+            self.state.is_capturing,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.label().hash(state);
+        self.prompt.hash(state);
+        self.placeholder.hash(state);
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+}
+
+/// The renderer of a [`ShortcutInput`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`ShortcutInput`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default padding of a [`ShortcutInput`].
+    const DEFAULT_PADDING: u16;
+
+    /// Draws a [`ShortcutInput`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ShortcutInput`]
+    ///   * the cursor position
+    ///   * the label of the [`ShortcutInput`]
+    ///   * whether the label is a shortcut or the prompt, instead of the
+    ///     placeholder
+    ///   * whether the [`ShortcutInput`] is capturing or not
+    ///   * the text size of the label
+    ///   * the font of the label
+    ///   * the style of the [`ShortcutInput`]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        label: &str,
+        is_assigned: bool,
+        is_capturing: bool,
+        text_size: u16,
+        font: Self::Font,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ShortcutInput<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        shortcut_input: ShortcutInput<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(shortcut_input)
+    }
+}
+
+/// The state of a [`ShortcutInput`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    is_capturing: bool,
+}
+
+impl State {
+    /// Creates a new [`State`], representing a [`ShortcutInput`] that is not
+    /// capturing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`ShortcutInput`] is capturing the next
+    /// [`Shortcut`] or not.
+    pub fn is_capturing(&self) -> bool {
+        self.is_capturing
+    }
+
+    /// Starts capturing the next [`Shortcut`].
+    pub fn capture(&mut self) {
+        self.is_capturing = true;
+    }
+
+    /// Stops capturing without producing a [`Shortcut`].
+    pub fn cancel(&mut self) {
+        self.is_capturing = false;
+    }
+}
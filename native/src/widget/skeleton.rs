@@ -0,0 +1,134 @@
+//! Hold the place of content that is still loading.
+use crate::layout;
+use crate::{Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget};
+
+use std::hash::Hash;
+
+/// A placeholder block shown while some content is loading, with an optional
+/// shimmer sweeping across it.
+///
+/// A [`Skeleton`] does not animate by itself. Advance its shimmer from your
+/// application with the `time::clock` subscription, which ticks on every
+/// frame boundary, using the time elapsed since the content started loading.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Length};
+/// #
+/// # pub type Skeleton = iced_native::Skeleton<Null>;
+/// # use std::time::Duration;
+/// let elapsed = Duration::from_millis(600);
+///
+/// let thumbnail = Skeleton::new(Length::Units(160), Length::Units(90))
+///     .shimmer(elapsed.as_secs_f32() / 1.5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Skeleton<Renderer: self::Renderer> {
+    width: Length,
+    height: Length,
+    shimmer: Option<f32>,
+    style: Renderer::Style,
+}
+
+impl<Renderer: self::Renderer> Skeleton<Renderer> {
+    /// Creates a new [`Skeleton`] with the given width and height.
+    pub fn new(width: Length, height: Length) -> Self {
+        Skeleton {
+            width,
+            height,
+            shimmer: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the progress of the shimmer of the [`Skeleton`].
+    ///
+    /// The shimmer sweeps across the [`Skeleton`] once as the progress goes
+    /// from `0.0` to `1.0`, and it starts over for every whole number.
+    pub fn shimmer(mut self, progress: f32) -> Self {
+        self.shimmer = Some(progress.rem_euclid(1.0));
+        self
+    }
+
+    /// Sets the style of the [`Skeleton`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Skeleton<Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(layout.bounds(), self.shimmer, &self.style)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`Skeleton`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Skeleton`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Skeleton`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Skeleton`]
+    ///   * the progress of the shimmer, between `0.0` and `1.0`, if any
+    ///   * the style of the [`Skeleton`]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        shimmer: Option<f32>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Skeleton<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+{
+    fn from(skeleton: Skeleton<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(skeleton)
+    }
+}
@@ -0,0 +1,279 @@
+//! Switch between views with a row of tabs.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::text;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A row of tabs, one of which is selected.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type TabBar<Message> = iced_native::TabBar<Message, Null>;
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     TabSelected(usize),
+/// }
+///
+/// let selected = 0;
+///
+/// let tab_bar = TabBar::new(
+///     vec![String::from("Library"), String::from("Player")],
+///     selected,
+///     Message::TabSelected,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TabBar<Message, Renderer: self::Renderer> {
+    labels: Vec<String>,
+    selected: usize,
+    on_select: Box<dyn Fn(usize) -> Message>,
+    width: Length,
+    spacing: u16,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: Renderer::Style,
+}
+
+impl<Message, Renderer: self::Renderer> TabBar<Message, Renderer> {
+    /// Creates a new [`TabBar`] with the given labels, the index of the
+    /// selected tab, and a function that produces a message when a tab is
+    /// selected.
+    pub fn new<F>(labels: Vec<String>, selected: usize, on_select: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        TabBar {
+            labels,
+            selected,
+            on_select: Box::new(on_select),
+            width: Length::Shrink,
+            spacing: 0,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`TabBar`].
+    ///
+    /// Any extra space is distributed evenly among the tabs.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the tabs of the [`TabBar`].
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding of every tab of the [`TabBar`].
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`TabBar`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the [`Font`] of the labels of the [`TabBar`].
+    ///
+    /// [`Font`]: crate::widget::text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`TabBar`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for TabBar<Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let padding = f32::from(self.padding);
+        let spacing = f32::from(self.spacing);
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        let tabs: Vec<Size> = self
+            .labels
+            .iter()
+            .map(|label| {
+                let (width, height) = renderer.measure(
+                    label,
+                    text_size,
+                    self.font,
+                    Size::INFINITY,
+                );
+
+                Size::new(width + padding * 2.0, height + padding * 2.0)
+            })
+            .collect();
+
+        let height = tabs.iter().map(|tab| tab.height).fold(0.0, f32::max);
+        let intrinsic_width = tabs.iter().map(|tab| tab.width).sum::<f32>()
+            + spacing * tabs.len().saturating_sub(1) as f32;
+
+        let size = limits.resolve(Size::new(intrinsic_width, height));
+
+        let extra = if tabs.is_empty() {
+            0.0
+        } else {
+            (size.width - intrinsic_width).max(0.0) / tabs.len() as f32
+        };
+
+        let mut x = 0.0;
+
+        let children = tabs
+            .into_iter()
+            .map(|tab| {
+                let mut node =
+                    layout::Node::new(Size::new(tab.width + extra, height));
+
+                node.move_to(Point::new(x, 0.0));
+                x += tab.width + extra + spacing;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(Size::new(size.width, height), children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            let clicked = layout
+                .children()
+                .position(|tab| tab.bounds().contains(cursor_position));
+
+            if let Some(index) = clicked {
+                messages.push((self.on_select)(index));
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout,
+            cursor_position,
+            &self.labels,
+            self.selected,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.labels.hash(state);
+        self.width.hash(state);
+        self.spacing.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+}
+
+/// The renderer of a [`TabBar`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TabBar`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default padding of the tabs of a [`TabBar`].
+    const DEFAULT_PADDING: u16;
+
+    /// Draws a [`TabBar`].
+    ///
+    /// It receives:
+    ///   * the [`Layout`] of the [`TabBar`], with a child for every tab
+    ///   * the cursor position
+    ///   * the labels of the tabs
+    ///   * the index of the selected tab
+    ///   * the text size of the labels
+    ///   * the font of the labels
+    ///   * the style of the [`TabBar`]
+    fn draw(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        labels: &[String],
+        selected: usize,
+        text_size: u16,
+        font: Self::Font,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<TabBar<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        tab_bar: TabBar<Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tab_bar)
+    }
+}
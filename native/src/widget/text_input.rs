@@ -16,8 +16,11 @@ use crate::keyboard;
 use crate::layout;
 use crate::mouse::{self, click};
 use crate::text;
+use crate::widget::operation::{self, Operation};
+use crate::widget::Id;
 use crate::{
-    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+    Clipboard, Command, Element, Hasher, Layout, Length, Point, Rectangle,
+    Size, Widget,
 };
 
 use std::u32;
@@ -49,6 +52,7 @@ use std::u32;
 #[allow(missing_debug_implementations)]
 pub struct TextInput<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
+    id: Option<Id>,
     placeholder: String,
     value: Value,
     is_secure: bool,
@@ -85,6 +89,7 @@ where
     {
         TextInput {
             state,
+            id: None,
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
@@ -99,6 +104,12 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`TextInput`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Converts the [`TextInput`] into a secure password input.
     pub fn password(mut self) -> Self {
         self.is_secure = true;
@@ -201,6 +212,21 @@ where
             )
         }
     }
+
+    /// Applies an [`Operation`] to the [`TextInput`].
+    ///
+    /// Widgets built on top of a [`TextInput`] can use this to let operations
+    /// reach it, whatever the messages they produce.
+    pub fn operate<T>(&mut self, operation: &mut dyn Operation<T>) {
+        operation.focusable(self.state, self.id.as_ref());
+        operation.text_input(
+            &mut Editing {
+                state: self.state,
+                value: &self.value,
+            },
+            self.id.as_ref(),
+        );
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -593,6 +619,14 @@ where
         self.padding.hash(state);
         self.size.hash(state);
     }
+
+    fn operate(
+        &mut self,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        TextInput::operate(self, operation);
+    }
 }
 
 /// The renderer of a [`TextInput`].
@@ -750,6 +784,78 @@ impl State {
     }
 }
 
+/// Produces a [`Command`] that focuses the [`TextInput`] with the given
+/// [`Id`], unfocusing any other widget.
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id))
+}
+
+/// Produces a [`Command`] that moves the cursor of the [`TextInput`] with the
+/// given [`Id`] to the front of its text.
+pub fn move_cursor_to_front<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::move_cursor_to_front(id))
+}
+
+/// Produces a [`Command`] that moves the cursor of the [`TextInput`] with the
+/// given [`Id`] to the end of its text.
+pub fn move_cursor_to_end<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::move_cursor_to_end(id))
+}
+
+/// Produces a [`Command`] that moves the cursor of the [`TextInput`] with the
+/// given [`Id`] to the given position.
+pub fn move_cursor_to<Message: 'static>(
+    id: Id,
+    position: usize,
+) -> Command<Message> {
+    Command::widget(operation::text_input::move_cursor_to(id, position))
+}
+
+/// Produces a [`Command`] that selects all the text of the [`TextInput`] with
+/// the given [`Id`].
+pub fn select_all<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::select_all(id))
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self)
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self)
+    }
+}
+
+/// The [`State`] of a [`TextInput`] together with its current [`Value`],
+/// which is needed to select it.
+struct Editing<'a> {
+    state: &'a mut State,
+    value: &'a Value,
+}
+
+impl<'a> operation::TextInput for Editing<'a> {
+    fn move_cursor_to_front(&mut self) {
+        self.state.move_cursor_to_front();
+    }
+
+    fn move_cursor_to_end(&mut self) {
+        self.state.move_cursor_to_end();
+    }
+
+    fn move_cursor_to(&mut self, position: usize) {
+        self.state.move_cursor_to(position);
+    }
+
+    fn select_all(&mut self) {
+        self.state.cursor.select_all(self.value);
+    }
+}
+
 // TODO: Reduce allocations
 fn find_cursor_position<Renderer: self::Renderer>(
     renderer: &Renderer,
@@ -0,0 +1,160 @@
+//! Zoom into some content and pan around it.
+use crate::{Point, Rectangle, Size, Vector};
+
+/// The zoom and pan of some content displayed inside some bounds.
+///
+/// The content is centered in its bounds initially. Its scale is relative to
+/// the size it has when it is not zoomed, which is usually the size that fits
+/// the bounds. The content cannot be panned beyond its edges.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    scale: f32,
+    offset: Vector,
+}
+
+impl Viewport {
+    /// Creates a new [`Viewport`] with content that is neither zoomed nor
+    /// panned.
+    pub fn new() -> Self {
+        Viewport {
+            scale: 1.0,
+            offset: Vector::new(0.0, 0.0),
+        }
+    }
+
+    /// Returns the current scale of the content.
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Returns the offset of the center of the content from the center of
+    /// its bounds.
+    pub fn offset(&self) -> Vector {
+        self.offset
+    }
+
+    /// Stops zooming and panning the content.
+    pub fn fit(&mut self) {
+        *self = Viewport::new();
+    }
+
+    /// Scales the content of the given size to the given scale, keeping the
+    /// point at the given position in place.
+    pub fn scale_to(
+        &mut self,
+        scale: f32,
+        bounds: Rectangle,
+        content: Size,
+        position: Point,
+    ) {
+        let position = position - bounds.center();
+        let under_position = position - self.offset;
+
+        self.offset = position - under_position * (scale / self.scale);
+        self.scale = scale;
+
+        self.clamp(bounds.size(), content);
+    }
+
+    /// Pans the content of the given size to the given offset.
+    pub fn pan_to(&mut self, offset: Vector, bounds: Size, content: Size) {
+        self.offset = offset;
+
+        self.clamp(bounds, content);
+    }
+
+    /// Returns the bounds of the zoomed and panned content of the given size,
+    /// which may exceed the given bounds.
+    pub fn content_bounds(
+        &self,
+        bounds: Rectangle,
+        content: Size,
+    ) -> Rectangle {
+        let size =
+            Size::new(content.width * self.scale, content.height * self.scale);
+
+        let center = bounds.center() + self.offset;
+
+        Rectangle {
+            x: center.x - size.width / 2.0,
+            y: center.y - size.height / 2.0,
+            width: size.width,
+            height: size.height,
+        }
+    }
+
+    /// Keeps the edges of the content from getting inside the given bounds,
+    /// centering it on any axis where it is smaller.
+    fn clamp(&mut self, bounds: Size, content: Size) {
+        let max_x =
+            ((content.width * self.scale - bounds.width) / 2.0).max(0.0);
+        let max_y =
+            ((content.height * self.scale - bounds.height) / 2.0).max(0.0);
+
+        self.offset = Vector::new(
+            self.offset.x.max(-max_x).min(max_x),
+            self.offset.y.max(-max_y).min(max_y),
+        );
+    }
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 200.0,
+        height: 100.0,
+    };
+
+    const CONTENT: Size = Size {
+        width: 200.0,
+        height: 100.0,
+    };
+
+    #[test]
+    fn scaling_keeps_the_point_under_the_position() {
+        let mut viewport = Viewport::new();
+        let position = Point::new(150.0, 75.0);
+
+        viewport.scale_to(2.0, BOUNDS, CONTENT, position);
+
+        let content = viewport.content_bounds(BOUNDS, CONTENT);
+
+        assert_eq!(viewport.scale(), 2.0);
+        assert_eq!(
+            Point::new(content.x + 150.0 * 2.0, content.y + 75.0 * 2.0),
+            position
+        );
+    }
+
+    #[test]
+    fn panning_stops_at_the_edges() {
+        let mut viewport = Viewport::new();
+
+        viewport.pan_to(Vector::new(50.0, 50.0), BOUNDS.size(), CONTENT);
+        assert_eq!(viewport.offset(), Vector::new(0.0, 0.0));
+
+        viewport.scale_to(2.0, BOUNDS, CONTENT, BOUNDS.center());
+        viewport.pan_to(Vector::new(500.0, -500.0), BOUNDS.size(), CONTENT);
+        assert_eq!(viewport.offset(), Vector::new(100.0, -50.0));
+    }
+
+    #[test]
+    fn fit_resets_scale_and_offset() {
+        let mut viewport = Viewport::new();
+
+        viewport.scale_to(3.0, BOUNDS, CONTENT, Point::ORIGIN);
+        viewport.fit();
+
+        assert_eq!(viewport, Viewport::new());
+    }
+}
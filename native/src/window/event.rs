@@ -12,6 +12,20 @@ pub enum Event {
         height: u32,
     },
 
+    /// A window was moved
+    Moved {
+        /// The new logical x location of the window
+        x: i32,
+
+        /// The new logical y location of the window
+        y: i32,
+    },
+
+    /// The user has requested for the window to close.
+    ///
+    /// Usually, the X button has been pressed.
+    CloseRequested,
+
     /// A file is being hovered over the window.
     ///
     /// When the user hovers multiple files at once, this event will be emitted
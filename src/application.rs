@@ -16,7 +16,8 @@ use crate::{Color, Command, Element, Executor, Settings, Subscription};
 /// interface.
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`, and an inspector that explains the layout
+/// of the widgets under the cursor can be toggled by pressing `Shift+F12`.
 ///
 /// # Examples
 /// [The repository has a bunch of examples] that use the [`Application`] trait:
@@ -178,6 +179,53 @@ pub trait Application: Sized {
         1.0
     }
 
+    /// Returns the [`Configuration`] of the [`Application`].
+    ///
+    /// The runtime will apply a new [`Configuration`] as soon as it is
+    /// returned. It can be used to change the default font, the default text
+    /// size or the antialiasing of the renderer at runtime (i.e. from a
+    /// settings screen).
+    ///
+    /// This is only available in native platforms, and antialiasing cannot
+    /// be changed at runtime when using the `glow` renderer.
+    ///
+    /// By default, it returns `None`, which keeps the [`Settings`] the
+    /// [`Application`] was run with. Returning `None` after a
+    /// [`Configuration`] restores them.
+    ///
+    /// [`Configuration`]: crate::settings::Configuration
+    #[cfg(not(target_arch = "wasm32"))]
+    fn configuration(&self) -> Option<crate::settings::Configuration> {
+        None
+    }
+
+    /// Returns whether the [`Application`] should exit when the user requests
+    /// its window to close.
+    ///
+    /// If it returns `false`, the runtime will produce a
+    /// `window::Event::CloseRequested` event instead, and you can decide when
+    /// to exit by means of [`should_exit`].
+    ///
+    /// Currently, this only has an effect in native platforms.
+    ///
+    /// By default, it returns `true`.
+    ///
+    /// [`should_exit`]: Self::should_exit
+    fn exit_on_close_request(&self) -> bool {
+        true
+    }
+
+    /// Returns whether the [`Application`] should exit.
+    ///
+    /// The runtime will check it after every update.
+    ///
+    /// Currently, this only has an effect in native platforms.
+    ///
+    /// By default, it returns `false`.
+    fn should_exit(&self) -> bool {
+        false
+    }
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -273,6 +321,18 @@ where
     fn scale_factor(&self) -> f64 {
         self.0.scale_factor()
     }
+
+    fn configuration(&self) -> Option<crate::renderer::window::Configuration> {
+        self.0.configuration()
+    }
+
+    fn exit_on_close_request(&self) -> bool {
+        self.0.exit_on_close_request()
+    }
+
+    fn should_exit(&self) -> bool {
+        self.0.should_exit()
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -1,2 +1,4 @@
 //! Listen and react to keyboard events.
-pub use crate::runtime::keyboard::{Event, KeyCode, Modifiers};
+pub use crate::runtime::keyboard::{
+    Event, KeyCode, Keymap, Modifiers, Shortcut,
+};
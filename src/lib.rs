@@ -187,6 +187,7 @@ pub mod executor;
 pub mod keyboard;
 pub mod mouse;
 pub mod settings;
+pub mod undo;
 pub mod widget;
 pub mod window;
 
@@ -204,6 +205,27 @@ pub mod window;
 )]
 pub mod time;
 
+#[cfg(all(feature = "notify", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "notify")))]
+pub mod subscription;
+
+#[cfg(all(feature = "persistence", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "persistence")))]
+pub mod persistence;
+
+#[cfg(all(feature = "theme", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "theme")))]
+pub mod theme;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use iced_futures::instance;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use iced_futures::launch;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use iced_futures::task;
+
 #[cfg(all(
     not(target_arch = "wasm32"),
     not(feature = "glow"),
@@ -239,6 +261,7 @@ pub use sandbox::Sandbox;
 pub use settings::Settings;
 
 pub use runtime::{
-    futures, Align, Background, Color, Command, Font, HorizontalAlignment,
-    Length, Point, Rectangle, Size, Subscription, Vector, VerticalAlignment,
+    cancellation, futures, Align, Background, Color, Command, Font,
+    HorizontalAlignment, Length, Point, Rectangle, Size, Subscription, Vector,
+    VerticalAlignment,
 };
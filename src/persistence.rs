@@ -0,0 +1,228 @@
+//! Save and restore the state of your application across runs.
+//!
+//! A [`Storage`] reads and writes a [`State`] to a file in the configuration
+//! directory of your application. A [`State`] contains the [`Geometry`] of the
+//! window together with any settings of your own, like the volume or the last
+//! opened file.
+//!
+//! Persistence is opt-in. Load the [`State`] before running your application
+//! to [`apply`] its [`Geometry`] to the window [`Settings`], keep the
+//! [`Geometry`] up to date with the window events of your application, and
+//! save the [`State`] whenever it changes.
+//!
+//! [`apply`]: Geometry::apply
+//! [`Settings`]: crate::window::Settings
+use crate::window;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// The persisted state of an application.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(crate = "serde")]
+pub struct State<T> {
+    /// The [`Geometry`] of the window.
+    #[serde(default)]
+    pub window: Geometry,
+
+    /// The settings of the application.
+    pub settings: T,
+}
+
+/// The size and position of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(crate = "serde")]
+pub struct Geometry {
+    /// The logical size of the window.
+    pub size: (u32, u32),
+
+    /// The logical position of the window, if known.
+    pub position: Option<(i32, i32)>,
+}
+
+impl Geometry {
+    /// Updates the [`Geometry`] with the given window event.
+    pub fn update(&mut self, event: &iced_winit::window::Event) {
+        use iced_winit::window::Event;
+
+        match *event {
+            Event::Resized { width, height } => {
+                self.size = (width, height);
+            }
+            Event::Moved { x, y } => {
+                self.position = Some((x, y));
+            }
+            _ => {}
+        }
+    }
+
+    /// Applies the [`Geometry`] to the given window [`Settings`].
+    ///
+    /// [`Settings`]: window::Settings
+    pub fn apply(&self, settings: &mut window::Settings) {
+        settings.size = self.size;
+        settings.position = self.position;
+    }
+}
+
+impl Default for Geometry {
+    fn default() -> Self {
+        let settings = window::Settings::default();
+
+        Geometry {
+            size: settings.size,
+            position: settings.position,
+        }
+    }
+}
+
+/// A file where the [`State`] of an application is stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Storage {
+    path: PathBuf,
+}
+
+impl Storage {
+    /// Creates a new [`Storage`] in the configuration directory of the
+    /// application identified by the given `qualifier`, `organization` and
+    /// `application` names.
+    ///
+    /// It returns `None` if no configuration directory could be found for the
+    /// current user.
+    pub fn new(
+        qualifier: &str,
+        organization: &str,
+        application: &str,
+    ) -> Option<Self> {
+        let directories = directories_next::ProjectDirs::from(
+            qualifier,
+            organization,
+            application,
+        )?;
+
+        Some(Self::at(directories.config_dir().join("state.json")))
+    }
+
+    /// Creates a new [`Storage`] at the given path.
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Storage { path: path.into() }
+    }
+
+    /// Returns the path of the [`Storage`].
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads the [`State`] from the [`Storage`].
+    pub fn load<T: DeserializeOwned>(&self) -> Result<State<T>, Error> {
+        let contents = std::fs::read(&self.path)?;
+
+        Ok(serde_json::from_slice(&contents)?)
+    }
+
+    /// Loads the [`State`] from the [`Storage`], falling back to its default
+    /// if it is missing or invalid.
+    pub fn load_or_default<T>(&self) -> State<T>
+    where
+        T: DeserializeOwned + Default,
+    {
+        self.load().unwrap_or_default()
+    }
+
+    /// Saves the [`State`] in the [`Storage`].
+    ///
+    /// The [`State`] is written to a temporary file first, so an interrupted
+    /// save never corrupts the previous one.
+    pub fn save<T: Serialize>(&self, state: &State<T>) -> Result<(), Error> {
+        let contents = serde_json::to_vec_pretty(state)?;
+        let temporary = self.path.with_extension("json.tmp");
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(&temporary, contents)?;
+        std::fs::rename(&temporary, &self.path)?;
+
+        Ok(())
+    }
+}
+
+/// An error that occurred while loading or saving a [`State`].
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The [`Storage`] could not be read or written.
+    #[error("the storage could not be accessed: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The [`State`] could not be serialized or deserialized.
+    #[error("the state could not be (de)serialized: {0}")]
+    Format(#[from] serde_json::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(crate = "serde")]
+    struct Settings {
+        volume: u8,
+        last_file: Option<String>,
+    }
+
+    fn storage(name: &str) -> Storage {
+        let directory = std::env::temp_dir().join(format!(
+            "iced-persistence-{}-{}",
+            name,
+            std::process::id()
+        ));
+
+        let _ = std::fs::remove_dir_all(&directory);
+
+        Storage::at(directory.join("state.json"))
+    }
+
+    #[test]
+    fn saved_state_loads_back() {
+        let storage = storage("round-trip");
+        let mut state = State {
+            window: Geometry::default(),
+            settings: Settings {
+                volume: 7,
+                last_file: Some(String::from("intro.mkv")),
+            },
+        };
+
+        state.window.update(&iced_winit::window::Event::Resized {
+            width: 640,
+            height: 480,
+        });
+        state
+            .window
+            .update(&iced_winit::window::Event::Moved { x: 10, y: 20 });
+
+        storage.save(&state).expect("Save state");
+
+        assert_eq!(storage.load::<Settings>().expect("Load state"), state);
+        assert_eq!(state.window.size, (640, 480));
+        assert_eq!(state.window.position, Some((10, 20)));
+
+        let _ = std::fs::remove_dir_all(storage.path().parent().unwrap());
+    }
+
+    #[test]
+    fn corrupt_state_falls_back_to_default() {
+        let storage = storage("corrupt");
+
+        std::fs::create_dir_all(storage.path().parent().unwrap())
+            .expect("Create storage directory");
+        std::fs::write(storage.path(), b"{ not json").expect("Corrupt state");
+
+        assert!(matches!(storage.load::<Settings>(), Err(Error::Format(_))));
+        assert_eq!(storage.load_or_default::<Settings>(), State::default());
+
+        let _ = std::fs::remove_dir_all(storage.path().parent().unwrap());
+    }
+}
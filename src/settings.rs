@@ -69,6 +69,24 @@ where
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::renderer::window::Configuration;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<Flags> From<&Settings<Flags>> for Configuration {
+    fn from(settings: &Settings<Flags>) -> Configuration {
+        Configuration {
+            default_font: settings.default_font,
+            default_text_size: settings.default_text_size,
+            antialiasing: if settings.antialiasing {
+                Some(crate::renderer::settings::Antialiasing::MSAAx4)
+            } else {
+                None
+            },
+        }
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 impl<Flags> From<Settings<Flags>> for iced_winit::Settings<Flags> {
     fn from(settings: Settings<Flags>) -> iced_winit::Settings<Flags> {
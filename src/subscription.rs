@@ -0,0 +1,31 @@
+//! Listen to external events in your application.
+use crate::Subscription;
+
+pub use iced_futures::fs::Change;
+
+/// Returns a [`Subscription`] that produces a [`Change`] every time a file
+/// or directory under the given `path` is created, removed or modified.
+///
+/// Directories are watched recursively. Renames are reported as a
+/// [`Change::Removed`] of the old path followed by a [`Change::Created`] of
+/// the new one.
+///
+/// Any other `path` is watched through its parent directory, so it keeps
+/// being watched after it is replaced; like editors do when saving a file
+/// atomically.
+pub fn watch_path(path: impl Into<std::path::PathBuf>) -> Subscription<Change> {
+    iced_futures::fs::watch_path(path)
+}
+
+/// Returns a [`Subscription`] that produces the given `path` every time the
+/// file at that path is written.
+///
+/// Like [`watch_path`], it watches the parent directory of the file. Unlike
+/// [`watch_path`], it ignores the file being removed. Therefore, replacing
+/// the file atomically only produces the `path` once the new contents are in
+/// place.
+pub fn watch_file(
+    path: impl Into<std::path::PathBuf>,
+) -> Subscription<std::path::PathBuf> {
+    iced_futures::fs::watch_file(path)
+}
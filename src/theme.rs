@@ -0,0 +1,261 @@
+//! Load style definitions from a file, and reload them while you iterate.
+//!
+//! A [`Theme`] holds named colors, border radii and paddings defined in a
+//! [RON] file, so your style sheets can look them up instead of hardcoding
+//! them:
+//!
+//! ```ron
+//! (
+//!     colors: {
+//!         "accent": "#3b82f6",
+//!         "overlay": "#000000b3",
+//!     },
+//!     radii: {
+//!         "button": 4.0,
+//!     },
+//!     paddings: {
+//!         "controls": 10,
+//!     },
+//! )
+//! ```
+//!
+//! Every section is optional. Colors are written as `#rrggbb` or `#rrggbbaa`.
+//!
+//! Load the [`Theme`] when your application starts and keep it in its state.
+//! With the `notify` feature enabled, [`Theme::watch`] reloads it every time
+//! the file changes in debug builds, so you can tweak it without recompiling.
+//!
+//! [RON]: https://github.com/ron-rs/ron
+use crate::Color;
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A set of named colors, border radii and paddings.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Theme {
+    colors: HashMap<String, Color>,
+    radii: HashMap<String, f32>,
+    paddings: HashMap<String, u16>,
+}
+
+/// The contents of a theme file, before parsing its colors.
+#[derive(Deserialize)]
+#[serde(crate = "serde")]
+struct Definitions {
+    #[serde(default)]
+    colors: HashMap<String, String>,
+
+    #[serde(default)]
+    radii: HashMap<String, f32>,
+
+    #[serde(default)]
+    paddings: HashMap<String, u16>,
+}
+
+impl Theme {
+    /// Parses a [`Theme`] from the contents of a theme file.
+    pub fn parse(contents: &str) -> Result<Self, Error> {
+        let definitions: Definitions = ron::de::from_str(contents)?;
+
+        let colors = definitions
+            .colors
+            .into_iter()
+            .map(|(name, value)| match parse_color(&value) {
+                Some(color) => Ok((name, color)),
+                None => Err(Error::InvalidColor { name, value }),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Theme {
+            colors,
+            radii: definitions.radii,
+            paddings: definitions.paddings,
+        })
+    }
+
+    /// Loads a [`Theme`] from the theme file at the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let contents = std::fs::read_to_string(path)?;
+
+        Self::parse(&contents)
+    }
+
+    /// Returns a [`Subscription`] that reloads the [`Theme`] from the theme
+    /// file at the given path every time it changes.
+    ///
+    /// Live reload is meant for development: in release builds, the
+    /// [`Subscription`] produces nothing.
+    ///
+    /// [`Subscription`]: crate::Subscription
+    #[cfg(feature = "notify")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "notify")))]
+    pub fn watch(
+        path: impl Into<std::path::PathBuf>,
+    ) -> crate::Subscription<Result<Self, Error>> {
+        if !cfg!(debug_assertions) {
+            return crate::Subscription::none();
+        }
+
+        crate::subscription::watch_file(path).map(Self::load)
+    }
+
+    /// Returns the color with the given name, if defined.
+    pub fn color(&self, name: &str) -> Option<Color> {
+        self.colors.get(name).copied()
+    }
+
+    /// Returns the border radius with the given name, if defined.
+    pub fn radius(&self, name: &str) -> Option<f32> {
+        self.radii.get(name).copied()
+    }
+
+    /// Returns the padding with the given name, if defined.
+    pub fn padding(&self, name: &str) -> Option<u16> {
+        self.paddings.get(name).copied()
+    }
+}
+
+/// An error that occurred while loading a [`Theme`].
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The theme file could not be read.
+    #[error("the theme file could not be read: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The theme file is not valid RON, or it has an unexpected structure.
+    #[error("the theme file could not be parsed: {0}")]
+    Format(#[from] ron::Error),
+
+    /// A color of the theme file is not written as `#rrggbb` or `#rrggbbaa`.
+    #[error("the color {name} has an invalid value: {value}")]
+    InvalidColor {
+        /// The name of the color.
+        name: String,
+
+        /// The invalid value of the color.
+        value: String,
+    },
+}
+
+fn parse_color(value: &str) -> Option<Color> {
+    let hex = value.strip_prefix('#')?;
+
+    // `from_str_radix` accepts a leading sign, and slicing needs ASCII
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+
+    match hex.len() {
+        6 => Some(Color::from_rgb8(channel(0)?, channel(2)?, channel(4)?)),
+        8 => Some(Color::from_rgba8(
+            channel(0)?,
+            channel(2)?,
+            channel(4)?,
+            f32::from(channel(6)?) / 255.0,
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_color_accepts_rgb_and_rgba() {
+        assert_eq!(
+            parse_color("#3b82f6"),
+            Some(Color::from_rgb8(0x3b, 0x82, 0xf6))
+        );
+        assert_eq!(
+            parse_color("#000000FF"),
+            Some(Color::from_rgba8(0, 0, 0, 1.0))
+        );
+        assert_eq!(
+            parse_color("#ffffff00"),
+            Some(Color::from_rgba8(255, 255, 255, 0.0))
+        );
+    }
+
+    #[test]
+    fn parse_color_rejects_short_and_long_colors() {
+        assert_eq!(parse_color("#"), None);
+        assert_eq!(parse_color("#fff"), None);
+        assert_eq!(parse_color("#fffff"), None);
+        assert_eq!(parse_color("#fffffff"), None);
+        assert_eq!(parse_color("#fffffffff"), None);
+    }
+
+    #[test]
+    fn parse_color_rejects_non_hex_colors() {
+        assert_eq!(parse_color("3b82f6"), None);
+        assert_eq!(parse_color("#3g82f6"), None);
+        assert_eq!(parse_color("#+b82f6"), None);
+        assert_eq!(parse_color("# b82f6"), None);
+    }
+
+    #[test]
+    fn parse_color_rejects_non_ascii_colors() {
+        // Both are six bytes long
+        assert_eq!(parse_color("#ffé00"), None);
+        assert_eq!(parse_color("#€fff"), None);
+    }
+
+    #[test]
+    fn parse_defaults_missing_sections() {
+        let theme = Theme::parse(r##"(colors: { "accent": "#3b82f6" })"##)
+            .expect("Parse theme");
+
+        assert_eq!(
+            theme.color("accent"),
+            Some(Color::from_rgb8(0x3b, 0x82, 0xf6))
+        );
+        assert_eq!(theme.radius("button"), None);
+        assert_eq!(theme.padding("controls"), None);
+
+        assert_eq!(Theme::parse("()").expect("Parse theme"), Theme::default());
+    }
+
+    #[test]
+    fn parse_reads_every_section() {
+        let theme = Theme::parse(
+            r##"(
+                colors: { "overlay": "#000000b3" },
+                radii: { "button": 4.0 },
+                paddings: { "controls": 10 },
+            )"##,
+        )
+        .expect("Parse theme");
+
+        assert_eq!(
+            theme.color("overlay"),
+            Some(Color::from_rgba8(0, 0, 0, 179.0 / 255.0))
+        );
+        assert_eq!(theme.radius("button"), Some(4.0));
+        assert_eq!(theme.padding("controls"), Some(10));
+    }
+
+    #[test]
+    fn parse_reports_invalid_colors() {
+        match Theme::parse(r##"(colors: { "accent": "blue" })"##) {
+            Err(Error::InvalidColor { name, value }) => {
+                assert_eq!(name, "accent");
+                assert_eq!(value, "blue");
+            }
+            other => panic!("Expected an invalid color, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_reports_malformed_files() {
+        assert!(matches!(
+            Theme::parse("(colors: 42)"),
+            Err(Error::Format(_))
+        ));
+        assert!(matches!(Theme::parse("(colors: {"), Err(Error::Format(_))));
+    }
+}
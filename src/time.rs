@@ -10,3 +10,13 @@ pub fn every(
 ) -> Subscription<std::time::Instant> {
     iced_futures::time::every(duration)
 }
+
+/// Returns a monotonic [`Subscription`] that produces the current time on
+/// every frame boundary.
+///
+/// All the [`clock`] subscriptions share the same timer, ticking 60 times per
+/// second. Prefer it over [`every`] when you need to drive position tickers,
+/// timers or animations.
+pub fn clock() -> Subscription<std::time::Instant> {
+    iced_futures::time::clock()
+}
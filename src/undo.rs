@@ -0,0 +1,351 @@
+//! Undo and redo changes to the state of your application.
+//!
+//! A [`History`] keeps snapshots of some state. Record a snapshot of the state
+//! right before changing it, and the [`History`] will give it back when the
+//! change is undone.
+//!
+//! ```
+//! use iced::undo::History;
+//!
+//! let mut history = History::new();
+//! let mut title = String::from("Untitled");
+//!
+//! history.record(title.clone());
+//! title.push_str(" (trimmed)");
+//!
+//! if let Some(previous) = history.undo(title.clone()) {
+//!     title = previous;
+//! }
+//!
+//! assert_eq!(title, "Untitled");
+//! ```
+//!
+//! Snapshots are simple to get right, and cloning is cheap for most
+//! application states. If it is not, keep the expensive parts of the state
+//! behind an `Rc` or an `Arc`.
+#[cfg(not(target_arch = "wasm32"))]
+use crate::keyboard::{self, KeyCode, Modifiers};
+
+/// A history of snapshots of some state that can be undone and redone.
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    undo: Vec<Entry<T>>,
+    redo: Vec<T>,
+    limit: Option<usize>,
+    is_sealed: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<T> {
+    snapshot: T,
+    group: Option<String>,
+}
+
+impl<T> History<T> {
+    /// Creates an empty [`History`] that can undo any number of changes.
+    pub fn new() -> Self {
+        History {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            limit: None,
+            is_sealed: false,
+        }
+    }
+
+    /// Creates an empty [`History`] that can undo up to the given number of
+    /// changes, forgetting the oldest ones first.
+    pub fn with_limit(limit: usize) -> Self {
+        History {
+            limit: Some(limit),
+            ..Self::new()
+        }
+    }
+
+    /// Records a snapshot of the state right before changing it.
+    ///
+    /// Recording a change forgets any undone change that could be redone.
+    pub fn record(&mut self, snapshot: T) {
+        self.push(Entry {
+            snapshot,
+            group: None,
+        });
+    }
+
+    /// Records a snapshot of the state right before changing it, coalescing
+    /// the change with the previous one if it was recorded with the same
+    /// group.
+    ///
+    /// Consecutive changes of the same group are undone as one, like the
+    /// keystrokes typed in a text field. Coalescing stops when a change of any
+    /// other group is recorded, when a change is undone or redone, and when
+    /// the [`History`] is [`seal`]ed.
+    ///
+    /// [`seal`]: Self::seal
+    pub fn record_coalesced(&mut self, snapshot: T, group: &str) {
+        let is_coalesced = !self.is_sealed
+            && self
+                .undo
+                .last()
+                .and_then(|entry| entry.group.as_deref())
+                .map(|last| last == group)
+                .unwrap_or(false);
+
+        if is_coalesced {
+            // The previous snapshot already holds the state before the group
+            self.redo.clear();
+        } else {
+            self.push(Entry {
+                snapshot,
+                group: Some(String::from(group)),
+            });
+        }
+    }
+
+    /// Stops coalescing changes into the last recorded one.
+    ///
+    /// This is useful when a text field loses focus, or when the state is
+    /// saved.
+    pub fn seal(&mut self) {
+        self.is_sealed = true;
+    }
+
+    /// Undoes the last change, given the current state.
+    ///
+    /// It returns the state to restore, or `None` if there is nothing to
+    /// undo.
+    pub fn undo(&mut self, current: T) -> Option<T> {
+        let entry = self.undo.pop()?;
+
+        self.redo.push(current);
+        self.is_sealed = true;
+
+        Some(entry.snapshot)
+    }
+
+    /// Redoes the last undone change, given the current state.
+    ///
+    /// It returns the state to restore, or `None` if there is nothing to
+    /// redo.
+    pub fn redo(&mut self, current: T) -> Option<T> {
+        let snapshot = self.redo.pop()?;
+
+        self.undo.push(Entry {
+            snapshot: current,
+            group: None,
+        });
+        self.is_sealed = true;
+
+        Some(snapshot)
+    }
+
+    /// Returns whether there is a change to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns whether there is an undone change to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forgets every change.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    fn push(&mut self, entry: Entry<T>) {
+        self.undo.push(entry);
+        self.redo.clear();
+        self.is_sealed = false;
+
+        if let Some(limit) = self.limit {
+            let excess = self.undo.len().saturating_sub(limit);
+
+            let _ = self.undo.drain(..excess);
+        }
+    }
+}
+
+impl<T> Default for History<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A request to undo or redo a change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Undo the last change.
+    Undo,
+
+    /// Redo the last undone change.
+    Redo,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Action {
+    /// Returns the [`Action`] requested by pressing the given key with the
+    /// given [`Modifiers`], following the conventions of the platform.
+    ///
+    /// The command key together with `Z` undoes, while adding `Shift` redoes.
+    /// The command key together with `Y` redoes too, except on macOS.
+    pub fn from_key(key_code: KeyCode, modifiers: Modifiers) -> Option<Self> {
+        if !modifiers.is_command_pressed() || modifiers.alt {
+            return None;
+        }
+
+        match key_code {
+            KeyCode::Z if modifiers.shift => Some(Action::Redo),
+            KeyCode::Z => Some(Action::Undo),
+            KeyCode::Y if !modifiers.shift && !cfg!(target_os = "macos") => {
+                Some(Action::Redo)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns a [`Subscription`] that produces an [`Action`] every time the
+/// standard undo or redo shortcuts are pressed.
+///
+/// Key presses captured by a widget are ignored, so a focused text input can
+/// keep handling its own shortcuts.
+///
+/// [`Subscription`]: crate::Subscription
+#[cfg(not(target_arch = "wasm32"))]
+pub fn actions() -> crate::Subscription<Action> {
+    use crate::runtime::event::{self, Event};
+
+    crate::runtime::subscription::events_with(|event, status| {
+        match (event, status) {
+            (
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                }),
+                event::Status::Ignored,
+            ) => Action::from_key(key_code, modifiers),
+            _ => None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Types the given text after the current one, like a text field would.
+    fn type_text(
+        history: &mut History<String>,
+        current: &mut String,
+        text: &str,
+    ) {
+        for c in text.chars() {
+            history.record_coalesced(current.clone(), "typing");
+            current.push(c);
+        }
+    }
+
+    #[test]
+    fn coalesced_changes_are_undone_as_one() {
+        let mut history = History::new();
+        let mut text = String::new();
+
+        type_text(&mut history, &mut text, "intro");
+
+        assert_eq!(history.undo(text), Some(String::new()));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn coalescing_stops_after_undo() {
+        let mut history = History::new();
+        let mut text = String::new();
+
+        type_text(&mut history, &mut text, "ab");
+        history.record(text.clone());
+        text.push('!');
+
+        text = history.undo(text).expect("Undo exclamation");
+        type_text(&mut history, &mut text, "cd");
+
+        assert_eq!(text, "abcd");
+        assert_eq!(history.undo(text), Some(String::from("ab")));
+        assert_eq!(history.undo(String::from("ab")), Some(String::new()));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn coalescing_stops_when_sealed_or_grouped_differently() {
+        let mut history = History::new();
+        let mut text = String::new();
+
+        type_text(&mut history, &mut text, "a");
+        history.seal();
+        type_text(&mut history, &mut text, "b");
+        history.record_coalesced(text.clone(), "paste");
+        text.push_str("cd");
+
+        assert_eq!(history.undo(text), Some(String::from("ab")));
+        assert_eq!(history.undo(String::from("ab")), Some(String::from("a")));
+        assert_eq!(history.undo(String::from("a")), Some(String::new()));
+    }
+
+    #[test]
+    fn recording_forgets_redo() {
+        let mut history = History::new();
+
+        history.record(1);
+        assert_eq!(history.undo(2), Some(1));
+        assert!(history.can_redo());
+
+        history.record(1);
+
+        assert!(!history.can_redo());
+        assert_eq!(history.redo(3), None);
+        assert_eq!(history.undo(3), Some(1));
+    }
+
+    #[test]
+    fn undo_and_redo_restore_states() {
+        let mut history = History::new();
+
+        history.record(1);
+        history.record(2);
+
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), Some(1));
+        assert_eq!(history.undo(1), None);
+        assert_eq!(history.redo(1), Some(2));
+        assert_eq!(history.redo(2), Some(3));
+        assert_eq!(history.redo(3), None);
+        assert_eq!(history.undo(3), Some(2));
+    }
+
+    #[test]
+    fn limit_forgets_oldest_changes() {
+        let mut history = History::with_limit(2);
+
+        history.record(1);
+        history.record(2);
+        history.record(3);
+
+        assert_eq!(history.undo(4), Some(3));
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), None);
+    }
+
+    #[test]
+    fn zero_limit_records_nothing() {
+        let mut history = History::with_limit(0);
+
+        history.record(1);
+        history.record_coalesced(2, "typing");
+        history.record_coalesced(3, "typing");
+
+        assert!(!history.can_undo());
+        assert_eq!(history.undo(4), None);
+        assert!(!history.can_redo());
+    }
+}
@@ -16,8 +16,11 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, pick_list, progress_bar, radio,
-        rule, scrollable, slider, text_input, Column, Row, Space, Text,
+        aspect_ratio, badge, breadcrumbs, button, checkbox, chip, container,
+        cursor_area, duration_input, lazy, number_input, pane_grid, pick_list,
+        progress_bar, radio, responsive, rule, scrollable, search_input,
+        shortcut_input, skeleton, slider, tab_bar, text_input, Column, Row,
+        Space, Text,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -40,6 +43,14 @@ mod platform {
         pub use crate::runtime::image::{Handle, Image};
     }
 
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub mod image_viewer {
+        //! Zoom into images and pan around them.
+        pub use crate::runtime::image_viewer::{ImageViewer, State};
+    }
+
+    pub use crate::runtime::widget::{operation, Id};
+
     #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
     pub mod svg {
         //! Display vector graphics in your user interface.
@@ -48,10 +59,15 @@ mod platform {
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
+        aspect_ratio::AspectRatio, badge::Badge, breadcrumbs::Breadcrumbs,
+        button::Button, checkbox::Checkbox, chip::Chip, container::Container,
+        cursor_area::CursorArea, duration_input::DurationInput, image::Image,
+        image_viewer::ImageViewer, lazy::Lazy, number_input::NumberInput,
         pane_grid::PaneGrid, pick_list::PickList, progress_bar::ProgressBar,
-        radio::Radio, rule::Rule, scrollable::Scrollable, slider::Slider,
-        svg::Svg, text_input::TextInput,
+        radio::Radio, responsive::Responsive, rule::Rule,
+        scrollable::Scrollable, search_input::SearchInput,
+        shortcut_input::ShortcutInput, skeleton::Skeleton, slider::Slider,
+        svg::Svg, tab_bar::TabBar, text_input::TextInput,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -6,6 +6,14 @@ pub struct Settings {
     /// The initial size of the window.
     pub size: (u32, u32),
 
+    /// The initial logical position of the window.
+    ///
+    /// If `None`, the platform decides where to place the window.
+    pub position: Option<(i32, i32)>,
+
+    /// Whether the window should start maximized or not.
+    pub maximized: bool,
+
     /// The minimum size of the window.
     pub min_size: Option<(u32, u32)>,
 
@@ -32,6 +40,8 @@ impl Default for Settings {
     fn default() -> Settings {
         Settings {
             size: (1024, 768),
+            position: None,
+            maximized: false,
             min_size: None,
             max_size: None,
             resizable: true,
@@ -48,6 +58,8 @@ impl From<Settings> for iced_winit::settings::Window {
     fn from(settings: Settings) -> Self {
         Self {
             size: settings.size,
+            position: settings.position,
+            maximized: settings.maximized,
             min_size: settings.min_size,
             max_size: settings.max_size,
             resizable: settings.resizable,
@@ -0,0 +1,46 @@
+//! Highlight counts, statuses and notifications with small badges.
+use iced_core::{Background, Color};
+
+/// The appearance of a badge.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Background,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub text_color: Color,
+}
+
+/// A set of rules that dictate the style of a badge.
+pub trait StyleSheet {
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            background: Background::Color(Color::from_rgb(0.85, 0.2, 0.2)),
+            border_radius: 10.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            text_color: Color::WHITE,
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
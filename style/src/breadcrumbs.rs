@@ -0,0 +1,44 @@
+//! Show and navigate the path to the current view.
+use iced_core::Color;
+
+/// The appearance of some breadcrumbs.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub segment_color: Color,
+    pub hovered_segment_color: Color,
+    pub current_segment_color: Color,
+    pub separator_color: Color,
+}
+
+/// A set of rules that dictate the style of some breadcrumbs.
+pub trait StyleSheet {
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            segment_color: Color::from_rgb(0.4, 0.4, 0.4),
+            hovered_segment_color: Color::from_rgb(0.2, 0.5, 0.8),
+            current_segment_color: Color::BLACK,
+            separator_color: Color::from_rgb(0.6, 0.6, 0.6),
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,73 @@
+//! Display compact labels that can be selected or pressed.
+use iced_core::{Background, Color};
+
+/// The appearance of a chip.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Background,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub text_color: Color,
+}
+
+/// A set of rules that dictate the style of a chip.
+pub trait StyleSheet {
+    fn active(&self, is_selected: bool) -> Style;
+
+    fn hovered(&self, is_selected: bool) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self, is_selected: bool) -> Style {
+        if is_selected {
+            Style {
+                background: Background::Color(Color::from_rgb(0.2, 0.5, 0.8)),
+                border_radius: 12.0,
+                border_width: 1.0,
+                border_color: Color::from_rgb(0.2, 0.5, 0.8),
+                text_color: Color::WHITE,
+            }
+        } else {
+            Style {
+                background: Background::Color(Color::from_rgb(
+                    0.95, 0.95, 0.95,
+                )),
+                border_radius: 12.0,
+                border_width: 1.0,
+                border_color: Color::from_rgb(0.7, 0.7, 0.7),
+                text_color: Color::BLACK,
+            }
+        }
+    }
+
+    fn hovered(&self, is_selected: bool) -> Style {
+        let active = self.active(is_selected);
+
+        Style {
+            background: if is_selected {
+                Background::Color(Color::from_rgb(0.25, 0.55, 0.85))
+            } else {
+                Background::Color(Color::from_rgb(0.90, 0.90, 0.90))
+            },
+            ..active
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
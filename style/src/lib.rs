@@ -6,14 +6,22 @@
 //! ![The foundations of the Iced ecosystem](https://github.com/hecrj/iced/blob/0525d76ff94e828b7b21634fa94a747022001c83/docs/graphs/foundations.png?raw=true)
 pub use iced_core::{Background, Color};
 
+pub mod badge;
+pub mod breadcrumbs;
 pub mod button;
 pub mod checkbox;
+pub mod chip;
 pub mod container;
 pub mod menu;
+pub mod number_input;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
 pub mod rule;
 pub mod scrollable;
+pub mod search_input;
+pub mod shortcut_input;
+pub mod skeleton;
 pub mod slider;
+pub mod tab_bar;
 pub mod text_input;
@@ -0,0 +1,60 @@
+//! Enter numbers within a range, typing them or stepping with spinners.
+use iced_core::{Background, Color};
+
+/// The appearance of the spinners of a number input.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Option<Background>,
+    pub border_radius: f32,
+    pub icon_color: Color,
+}
+
+/// A set of rules that dictate the style of the spinners of a number input.
+pub trait StyleSheet {
+    fn active(&self) -> Style;
+
+    fn hovered(&self) -> Style;
+
+    fn disabled(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            background: None,
+            border_radius: 2.0,
+            icon_color: Color::from_rgb(0.4, 0.4, 0.4),
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            background: Some(Background::Color(Color::from_rgb(0.9, 0.9, 0.9))),
+            ..self.active()
+        }
+    }
+
+    fn disabled(&self) -> Style {
+        Style {
+            icon_color: Color::from_rgb(0.8, 0.8, 0.8),
+            ..self.active()
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
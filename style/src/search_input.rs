@@ -0,0 +1,50 @@
+//! Filter large lists with a text field that debounces its searches.
+use iced_core::Color;
+
+/// The appearance of the search icon and the clear button of a search input.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub icon_color: Color,
+    pub clear_color: Color,
+}
+
+/// A set of rules that dictate the style of the search icon and the clear
+/// button of a search input.
+pub trait StyleSheet {
+    fn active(&self) -> Style;
+
+    fn clear_hovered(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            icon_color: Color::from_rgb(0.6, 0.6, 0.6),
+            clear_color: Color::from_rgb(0.6, 0.6, 0.6),
+        }
+    }
+
+    fn clear_hovered(&self) -> Style {
+        Style {
+            clear_color: Color::from_rgb(0.3, 0.3, 0.3),
+            ..self.active()
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
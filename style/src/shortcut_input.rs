@@ -0,0 +1,66 @@
+//! Let users rebind keyboard shortcuts by pressing them.
+use iced_core::{Background, Color};
+
+/// The appearance of a shortcut input.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Background,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub text_color: Color,
+    pub placeholder_color: Color,
+}
+
+/// A set of rules that dictate the style of a shortcut input.
+pub trait StyleSheet {
+    fn active(&self) -> Style;
+
+    fn hovered(&self) -> Style;
+
+    fn capturing(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            background: Background::Color(Color::WHITE),
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            text_color: Color::from_rgb(0.3, 0.3, 0.3),
+            placeholder_color: Color::from_rgb(0.7, 0.7, 0.7),
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            border_color: Color::from_rgb(0.5, 0.5, 0.5),
+            ..self.active()
+        }
+    }
+
+    fn capturing(&self) -> Style {
+        Style {
+            border_color: Color::from_rgb(0.5, 0.5, 0.7),
+            ..self.active()
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,42 @@
+//! Hold the place of content that is still loading.
+use iced_core::Color;
+
+/// The appearance of a skeleton.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Color,
+    pub shimmer_color: Color,
+    pub border_radius: f32,
+}
+
+/// A set of rules that dictate the style of a skeleton.
+pub trait StyleSheet {
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            background: Color::from_rgb(0.88, 0.88, 0.88),
+            shimmer_color: Color::from_rgb(0.96, 0.96, 0.96),
+            border_radius: 4.0,
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,67 @@
+//! Switch between views with a row of tabs.
+use iced_core::{Background, Color};
+
+/// The appearance of a tab.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Option<Background>,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub text_color: Color,
+    pub indicator_color: Option<Color>,
+}
+
+/// A set of rules that dictate the style of a tab bar.
+pub trait StyleSheet {
+    fn active(&self, is_selected: bool) -> Style;
+
+    fn hovered(&self, is_selected: bool) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self, is_selected: bool) -> Style {
+        Style {
+            background: None,
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            text_color: if is_selected {
+                Color::BLACK
+            } else {
+                Color::from_rgb(0.4, 0.4, 0.4)
+            },
+            indicator_color: if is_selected {
+                Some(Color::from_rgb(0.2, 0.5, 0.8))
+            } else {
+                None
+            },
+        }
+    }
+
+    fn hovered(&self, is_selected: bool) -> Style {
+        Style {
+            background: Some(Background::Color(Color::from_rgb(
+                0.95, 0.95, 0.95,
+            ))),
+            ..self.active(is_selected)
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
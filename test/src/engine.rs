@@ -0,0 +1,69 @@
+use crate::executor::{self, Executor};
+
+use iced_futures::futures::channel::mpsc;
+use iced_futures::futures::executor::LocalPool;
+use iced_futures::futures::task::LocalSpawnExt;
+use iced_native::event::{self, Event};
+use iced_native::Subscription;
+
+/// Runs commands and subscriptions in the current thread.
+pub(crate) struct Engine<Message> {
+    runtime:
+        iced_native::Runtime<Executor, mpsc::UnboundedSender<Message>, Message>,
+    pool: LocalPool,
+    queue: executor::Queue,
+    receiver: mpsc::UnboundedReceiver<Message>,
+}
+
+impl<Message> Engine<Message>
+where
+    Message: Send + 'static,
+{
+    pub(crate) fn new() -> Self {
+        let executor = Executor::default();
+        let queue = executor.queue();
+        let (sender, receiver) = mpsc::unbounded();
+
+        Engine {
+            runtime: iced_native::Runtime::new(executor, sender),
+            pool: LocalPool::new(),
+            queue,
+            receiver,
+        }
+    }
+
+    pub(crate) fn spawn(&mut self, command: iced_futures::Command<Message>) {
+        self.runtime.spawn(command);
+    }
+
+    pub(crate) fn track(&mut self, subscription: Subscription<Message>) {
+        self.runtime.track(subscription);
+    }
+
+    pub(crate) fn broadcast(&mut self, event: Event, status: event::Status) {
+        self.runtime.broadcast((event, status));
+    }
+
+    /// Runs the spawned futures until none of them can make progress,
+    /// returning the messages they produced.
+    ///
+    /// No messages means the [`Engine`] has settled.
+    pub(crate) fn run_until_stalled(&mut self) -> Vec<Message> {
+        for future in self.queue.drain() {
+            self.pool
+                .spawner()
+                .spawn_local(future)
+                .expect("Spawn future in local pool");
+        }
+
+        self.pool.run_until_stalled();
+
+        let mut messages = Vec::new();
+
+        while let Ok(message) = self.receiver.try_recv() {
+            messages.push(message);
+        }
+
+        messages
+    }
+}
@@ -0,0 +1,52 @@
+use iced_futures::futures::future::{BoxFuture, Future, FutureExt};
+
+use std::sync::{Arc, Mutex};
+
+/// An executor that queues futures instead of running them.
+///
+/// A [`Runtime`] runs the queued futures in the current thread when it
+/// settles, which keeps the order of their results deterministic.
+///
+/// [`Runtime`]: crate::Runtime
+#[derive(Debug, Default)]
+pub struct Executor {
+    queue: Queue,
+}
+
+impl Executor {
+    pub(crate) fn queue(&self) -> Queue {
+        self.queue.clone()
+    }
+}
+
+impl iced_futures::Executor for Executor {
+    fn new() -> Result<Self, iced_futures::futures::io::Error> {
+        Ok(Self::default())
+    }
+
+    fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
+        self.queue.push(future.boxed());
+    }
+}
+
+/// The futures spawned in an [`Executor`], waiting to run.
+#[derive(Clone, Default)]
+pub(crate) struct Queue(Arc<Mutex<Vec<BoxFuture<'static, ()>>>>);
+
+impl Queue {
+    fn push(&self, future: BoxFuture<'static, ()>) {
+        self.0.lock().expect("Lock future queue").push(future);
+    }
+
+    pub(crate) fn drain(&self) -> Vec<BoxFuture<'static, ()>> {
+        std::mem::take(&mut *self.0.lock().expect("Lock future queue"))
+    }
+}
+
+impl std::fmt::Debug for Queue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Queue")
+            .field("len", &self.0.lock().map(|queue| queue.len()).ok())
+            .finish()
+    }
+}
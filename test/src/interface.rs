@@ -0,0 +1,506 @@
+use crate::engine::Engine;
+
+use iced_native::keyboard::{self, KeyCode, Modifiers};
+use iced_native::mouse;
+use iced_native::widget::operation::Outcome;
+use iced_native::{
+    Cache, Command, Event, Point, Program, Rectangle, Size, UserInterface,
+};
+
+/// A headless user interface of a [`Program`] that can be driven with
+/// simulated input.
+///
+/// An [`Interface`] lays out the widgets of the [`Program`] like a shell
+/// would, and dispatches synthetic mouse and keyboard events through that
+/// layout. The messages produced run through the update logic of the
+/// [`Program`] and its commands run like in a [`Runtime`], including their
+/// operations on widgets.
+///
+/// You can drive a [`Program`] drawn with any renderer. The
+/// [`Null`](iced_native::renderer::Null) renderer works in any environment,
+/// but it measures every line of text as empty, so keep that in mind when
+/// choosing the positions to interact with.
+///
+/// An [`Interface`] only drives a [`Program`], not an `iced` [`Application`],
+/// whose renderer needs a graphics device. A [`Program`] has no
+/// subscriptions, so none are tracked; test them with a [`Runtime`] instead.
+/// Like a [`Runtime`], an [`Interface`] panics if it does not settle after
+/// [`MAX_ROUNDS`].
+///
+/// # Example
+/// ```
+/// use iced_native::renderer::Null;
+/// use iced_native::{button, Button, Command, Element, Point, Program, Size, Text};
+/// use iced_test::Interface;
+///
+/// #[derive(Default)]
+/// struct Player {
+///     play: button::State,
+///     is_playing: bool,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Play,
+/// }
+///
+/// impl Program for Player {
+///     type Renderer = Null;
+///     type Message = Message;
+///
+///     fn update(&mut self, message: Message) -> Command<Message> {
+///         match message {
+///             Message::Play => self.is_playing = true,
+///         }
+///
+///         Command::none()
+///     }
+///
+///     fn view(&mut self) -> Element<'_, Message, Null> {
+///         Button::new(&mut self.play, Text::new("Play"))
+///             .padding(10)
+///             .on_press(Message::Play)
+///             .into()
+///     }
+/// }
+///
+/// let mut interface =
+///     Interface::new(Player::default(), Null::new(), Size::new(800.0, 600.0));
+///
+/// interface.click(Point::new(5.0, 5.0));
+///
+/// assert!(interface.program().is_playing);
+/// ```
+///
+/// [`Runtime`]: crate::Runtime
+/// [`Application`]: iced::Application
+/// [`MAX_ROUNDS`]: crate::MAX_ROUNDS
+#[allow(missing_debug_implementations)]
+pub struct Interface<P: Program> {
+    program: P,
+    renderer: P::Renderer,
+    engine: Engine<P::Message>,
+    bounds: Size,
+    cache: Option<Cache>,
+    cursor_position: Point,
+    modifiers: Modifiers,
+    messages: Vec<P::Message>,
+}
+
+impl<P> Interface<P>
+where
+    P: Program,
+    P::Message: Clone + 'static,
+{
+    /// Creates a new [`Interface`] of the given [`Program`], laid out with
+    /// the given renderer in a window of the given size.
+    pub fn new(program: P, renderer: P::Renderer, bounds: Size) -> Self {
+        Interface {
+            program,
+            renderer,
+            engine: Engine::new(),
+            bounds,
+            cache: Some(Cache::default()),
+            cursor_position: Point::new(-1.0, -1.0),
+            modifiers: Modifiers::default(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Returns the [`Program`] of the [`Interface`].
+    pub fn program(&self) -> &P {
+        &self.program
+    }
+
+    /// Returns the [`Program`] of the [`Interface`] mutably.
+    pub fn program_mut(&mut self) -> &mut P {
+        &mut self.program
+    }
+
+    /// Returns every message processed by the [`Program`] so far, in order.
+    pub fn messages(&self) -> &[P::Message] {
+        &self.messages
+    }
+
+    /// Clears the messages processed so far.
+    pub fn clear_messages(&mut self) {
+        self.messages.clear();
+    }
+
+    /// Returns the current position of the simulated mouse cursor.
+    ///
+    /// The cursor starts outside of the window.
+    pub fn cursor_position(&self) -> Point {
+        self.cursor_position
+    }
+
+    /// Returns the bounds of the widgets laid out at the given position,
+    /// from the outermost to the innermost.
+    ///
+    /// Use it to check that a position hits the widget you expect before
+    /// interacting with it.
+    pub fn bounds_at(&mut self, position: Point) -> Vec<Rectangle> {
+        let user_interface = build(
+            &mut self.program,
+            &mut self.renderer,
+            self.bounds,
+            self.cache.take(),
+        );
+        let bounds = user_interface.bounds_at(position);
+
+        self.cache = Some(user_interface.into_cache());
+
+        bounds
+    }
+
+    /// Resizes the window of the [`Interface`].
+    pub fn resize(&mut self, bounds: Size) {
+        self.bounds = bounds;
+
+        self.event(Event::Window(iced_native::window::Event::Resized {
+            width: bounds.width as u32,
+            height: bounds.height as u32,
+        }));
+    }
+
+    /// Feeds the given message to the [`Program`], processing every message
+    /// produced as a result.
+    pub fn update(&mut self, message: P::Message) {
+        self.process(vec![message]);
+    }
+
+    /// Dispatches the given [`Event`] through the widgets of the [`Program`],
+    /// processing every message produced as a result.
+    ///
+    /// The [`Event`] is dispatched at the current position of the cursor.
+    pub fn event(&mut self, event: Event) {
+        let mut user_interface = build(
+            &mut self.program,
+            &mut self.renderer,
+            self.bounds,
+            self.cache.take(),
+        );
+        let mut messages = Vec::new();
+
+        let _ = user_interface.update(
+            &[event],
+            self.cursor_position,
+            None,
+            &self.renderer,
+            &mut messages,
+        );
+
+        self.cache = Some(user_interface.into_cache());
+
+        self.process(messages);
+    }
+
+    /// Moves the cursor to the given position.
+    pub fn move_cursor_to(&mut self, position: Point) {
+        self.cursor_position = position;
+
+        self.event(Event::Mouse(mouse::Event::CursorMoved {
+            x: position.x,
+            y: position.y,
+        }));
+    }
+
+    /// Presses the given mouse button at the current position of the cursor.
+    pub fn press(&mut self, button: mouse::Button) {
+        self.event(Event::Mouse(mouse::Event::ButtonPressed(button)));
+    }
+
+    /// Releases the given mouse button at the current position of the
+    /// cursor.
+    pub fn release(&mut self, button: mouse::Button) {
+        self.event(Event::Mouse(mouse::Event::ButtonReleased(button)));
+    }
+
+    /// Moves the cursor to the given position and clicks the left mouse
+    /// button there.
+    pub fn click(&mut self, position: Point) {
+        self.move_cursor_to(position);
+        self.press(mouse::Button::Left);
+        self.release(mouse::Button::Left);
+    }
+
+    /// Drags with the left mouse button from one position to another.
+    pub fn drag(&mut self, from: Point, to: Point) {
+        self.move_cursor_to(from);
+        self.press(mouse::Button::Left);
+        self.move_cursor_to(to);
+        self.release(mouse::Button::Left);
+    }
+
+    /// Scrolls the mouse wheel at the current position of the cursor.
+    pub fn scroll(&mut self, delta: mouse::ScrollDelta) {
+        self.event(Event::Mouse(mouse::Event::WheelScrolled { delta }));
+    }
+
+    /// Sets the keyboard modifiers that are held down.
+    pub fn set_modifiers(&mut self, modifiers: Modifiers) {
+        self.modifiers = modifiers;
+
+        self.event(Event::Keyboard(keyboard::Event::ModifiersChanged(
+            modifiers,
+        )));
+    }
+
+    /// Presses and releases the given key.
+    pub fn tap_key(&mut self, key_code: KeyCode) {
+        let modifiers = self.modifiers;
+
+        self.event(Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+        }));
+        self.event(Event::Keyboard(keyboard::Event::KeyReleased {
+            key_code,
+            modifiers,
+        }));
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.event(Event::Keyboard(keyboard::Event::CharacterReceived(c)));
+        }
+    }
+
+    fn process(&mut self, mut messages: Vec<P::Message>) {
+        let mut rounds = 0;
+
+        while !messages.is_empty() {
+            rounds += 1;
+
+            if rounds > crate::MAX_ROUNDS {
+                panic!(
+                    "The interface did not settle after {} rounds of messages",
+                    crate::MAX_ROUNDS
+                );
+            }
+
+            for message in messages {
+                self.messages.push(message.clone());
+
+                let command = self.program.update(message);
+
+                self.run(command);
+            }
+
+            messages = self.engine.run_until_stalled();
+        }
+    }
+
+    fn run(&mut self, command: Command<P::Message>) {
+        let (futures, operations) = command.into_parts();
+
+        self.engine.spawn(futures);
+
+        if operations.is_empty() {
+            return;
+        }
+
+        let mut messages = Vec::new();
+        let mut user_interface = build(
+            &mut self.program,
+            &mut self.renderer,
+            self.bounds,
+            self.cache.take(),
+        );
+
+        for mut operation in operations {
+            loop {
+                user_interface.operate(operation.as_mut());
+
+                match operation.finish() {
+                    Outcome::None => break,
+                    Outcome::Some(message) => {
+                        messages.push(message);
+                        break;
+                    }
+                    Outcome::Chain(next) => {
+                        operation = next;
+                    }
+                }
+            }
+        }
+
+        self.cache = Some(user_interface.into_cache());
+
+        self.process(messages);
+    }
+}
+
+fn build<'a, P: Program>(
+    program: &'a mut P,
+    renderer: &mut P::Renderer,
+    bounds: Size,
+    cache: Option<Cache>,
+) -> UserInterface<'a, P::Message, P::Renderer> {
+    UserInterface::build(
+        program.view(),
+        bounds,
+        cache.unwrap_or_default(),
+        renderer,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_native::keyboard::Shortcut;
+    use iced_native::renderer::Null;
+    use iced_native::{
+        shortcut_input, slider, text_input, Column, Element, Length,
+        ShortcutInput, Slider, TextInput,
+    };
+
+    #[derive(Default)]
+    struct Form {
+        volume: u8,
+        name: String,
+        shortcut: Option<Shortcut>,
+        slider: slider::State,
+        text_input: text_input::State,
+        shortcut_input: shortcut_input::State,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        VolumeChanged(u8),
+        NameChanged(String),
+        Rebound(Shortcut),
+        Loop,
+    }
+
+    impl Program for Form {
+        type Renderer = Null;
+        type Message = Message;
+
+        fn update(&mut self, message: Message) -> Command<Message> {
+            match message {
+                Message::VolumeChanged(volume) => self.volume = volume,
+                Message::NameChanged(name) => self.name = name,
+                Message::Rebound(shortcut) => self.shortcut = Some(shortcut),
+                Message::Loop => {
+                    return Command::from(async { Message::Loop });
+                }
+            }
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, Message, Null> {
+            // Every widget is 100x20, stacked from the top
+            Column::new()
+                .width(Length::Units(100))
+                .push(
+                    Slider::new(
+                        &mut self.slider,
+                        0..=100,
+                        self.volume,
+                        Message::VolumeChanged,
+                    )
+                    .height(20),
+                )
+                .push(
+                    TextInput::new(
+                        &mut self.text_input,
+                        "Name",
+                        &self.name,
+                        Message::NameChanged,
+                    )
+                    .padding(0)
+                    .size(20),
+                )
+                .push(
+                    ShortcutInput::new(
+                        &mut self.shortcut_input,
+                        self.shortcut,
+                        Message::Rebound,
+                    )
+                    .width(Length::Fill)
+                    .padding(0)
+                    .text_size(20),
+                )
+                .into()
+        }
+    }
+
+    fn interface() -> Interface<Form> {
+        Interface::new(Form::default(), Null::new(), Size::new(800.0, 600.0))
+    }
+
+    #[test]
+    fn drag_moves_the_cursor_while_pressed() {
+        let mut interface = interface();
+
+        interface.drag(Point::new(10.0, 10.0), Point::new(50.0, 10.0));
+
+        assert_eq!(
+            interface.messages(),
+            &[Message::VolumeChanged(10), Message::VolumeChanged(50)]
+        );
+        assert_eq!(interface.cursor_position(), Point::new(50.0, 10.0));
+
+        interface.move_cursor_to(Point::new(90.0, 10.0));
+
+        assert_eq!(interface.program().volume, 50);
+    }
+
+    #[test]
+    fn type_text_types_each_character() {
+        let mut interface = interface();
+
+        interface.click(Point::new(50.0, 30.0));
+        interface.type_text("iced");
+
+        assert_eq!(
+            interface.messages(),
+            &[
+                Message::NameChanged(String::from("i")),
+                Message::NameChanged(String::from("ic")),
+                Message::NameChanged(String::from("ice")),
+                Message::NameChanged(String::from("iced")),
+            ]
+        );
+        assert_eq!(interface.program().name, "iced");
+    }
+
+    #[test]
+    fn tap_key_holds_the_current_modifiers() {
+        let mut interface = interface();
+        let control = Modifiers {
+            control: true,
+            ..Modifiers::default()
+        };
+
+        interface.click(Point::new(50.0, 50.0));
+        interface.set_modifiers(control);
+        interface.tap_key(KeyCode::S);
+
+        assert_eq!(
+            interface.messages(),
+            &[Message::Rebound(Shortcut::new(KeyCode::S, control))]
+        );
+
+        interface.set_modifiers(Modifiers::default());
+        interface.click(Point::new(50.0, 50.0));
+        interface.tap_key(KeyCode::P);
+
+        assert_eq!(
+            interface.program().shortcut,
+            Some(Shortcut::new(KeyCode::P, Modifiers::default()))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "did not settle")]
+    fn gives_up_on_endless_messages() {
+        let mut interface = interface();
+
+        interface.update(Message::Loop);
+    }
+}
@@ -0,0 +1,390 @@
+//! A deterministic, headless runtime to test the logic of Iced applications.
+//!
+//! A [`Runtime`] runs the update loop of an [`Application`] without opening a
+//! window. It feeds the application the messages you provide, runs the
+//! resulting commands, and keeps its subscriptions alive, so you can inject
+//! events and assert on the messages produced and the state reached.
+//!
+//! An [`Interface`] goes one step further and lays out the widgets of an
+//! `iced_native` [`Program`], so you can script clicks, drags and key presses
+//! against the real layout of your user interface. It cannot drive an
+//! [`Application`] directly, nor track subscriptions; test those with a
+//! [`Runtime`].
+//!
+//! [`Program`]: iced_native::Program
+//!
+//! # Example
+//! ```
+//! use iced::{executor, Application, Command, Element, Text};
+//! use iced_test::Runtime;
+//!
+//! #[derive(Default)]
+//! struct Counter {
+//!     value: i32,
+//! }
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     Increment,
+//!     Reset,
+//! }
+//!
+//! impl Application for Counter {
+//!     type Executor = executor::Default;
+//!     type Message = Message;
+//!     type Flags = ();
+//!
+//!     fn new(_flags: ()) -> (Counter, Command<Message>) {
+//!         (Counter::default(), Command::none())
+//!     }
+//!
+//!     fn title(&self) -> String {
+//!         String::from("Counter")
+//!     }
+//!
+//!     fn update(&mut self, message: Message) -> Command<Message> {
+//!         match message {
+//!             Message::Increment if self.value == 2 => {
+//!                 self.value += 1;
+//!
+//!                 Command::from(async { Message::Reset })
+//!             }
+//!             Message::Increment => {
+//!                 self.value += 1;
+//!
+//!                 Command::none()
+//!             }
+//!             Message::Reset => {
+//!                 self.value = 0;
+//!
+//!                 Command::none()
+//!             }
+//!         }
+//!     }
+//!
+//!     fn view(&mut self) -> Element<Message> {
+//!         Text::new(self.value.to_string()).into()
+//!     }
+//! }
+//!
+//! let mut runtime = Runtime::<Counter>::new(());
+//!
+//! runtime.update(Message::Increment);
+//! runtime.update(Message::Increment);
+//! assert_eq!(runtime.application().value, 2);
+//!
+//! runtime.update(Message::Increment);
+//! assert_eq!(runtime.application().value, 0);
+//! assert_eq!(runtime.messages().last(), Some(&Message::Reset));
+//! ```
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![deny(unused_results)]
+#![forbid(unsafe_code)]
+#![forbid(rust_2018_idioms)]
+mod engine;
+mod executor;
+mod interface;
+
+pub use executor::Executor;
+pub use interface::Interface;
+
+use engine::Engine;
+
+use iced::Application;
+use iced_native::event::{self, Event};
+use iced_native::widget::Operation;
+
+/// A deterministic, headless runtime of an [`Application`].
+///
+/// The futures of commands and subscriptions run in the current thread, in
+/// the order they are spawned, until none of them can make progress.
+///
+/// There is no controllable clock. Futures waiting on timers or other
+/// external sources may never finish, so tests should inject the messages or
+/// events they would produce instead. Conversely, a [`Runtime`] gives up
+/// settling after [`MAX_ROUNDS`] rounds of messages, which usually means a
+/// command or subscription keeps producing messages forever. A future that
+/// never stops being ready is never interrupted, though.
+///
+/// Commands may also carry operations on widgets. A [`Runtime`] has no user
+/// interface to run them on, so it keeps them until you [`take_operations`].
+///
+/// [`take_operations`]: Self::take_operations
+///
+/// Messages must implement `Clone`, so the [`Runtime`] can keep a log of them.
+#[allow(missing_debug_implementations)]
+pub struct Runtime<A: Application> {
+    application: A,
+    engine: Engine<A::Message>,
+    messages: Vec<A::Message>,
+    operations: Vec<Box<dyn Operation<A::Message>>>,
+}
+
+/// The maximum amount of rounds of messages a [`Runtime`] processes before
+/// giving up on settling.
+///
+/// A round processes the messages produced by the futures that made progress
+/// during the previous one.
+pub const MAX_ROUNDS: usize = 1_000;
+
+impl<A> Runtime<A>
+where
+    A: Application,
+    A::Message: Clone + 'static,
+{
+    /// Creates a new [`Runtime`], initializing the [`Application`] with the
+    /// given flags.
+    ///
+    /// The command returned by [`Application::new`] runs right away.
+    pub fn new(flags: A::Flags) -> Self {
+        let (application, command) = A::new(flags);
+
+        let mut runtime = Runtime {
+            application,
+            engine: Engine::new(),
+            messages: Vec::new(),
+            operations: Vec::new(),
+        };
+
+        runtime.spawn(command);
+        runtime.settle();
+
+        runtime
+    }
+
+    /// Returns the [`Application`] of the [`Runtime`].
+    pub fn application(&self) -> &A {
+        &self.application
+    }
+
+    /// Returns the [`Application`] of the [`Runtime`] mutably.
+    ///
+    /// Changes made to the [`Application`] this way do not update its
+    /// subscriptions until the next message is processed.
+    pub fn application_mut(&mut self) -> &mut A {
+        &mut self.application
+    }
+
+    /// Returns every message processed by the [`Application`] so far, in
+    /// order.
+    ///
+    /// This includes both the messages fed with [`update`] and the messages
+    /// produced by commands and subscriptions.
+    ///
+    /// [`update`]: Self::update
+    pub fn messages(&self) -> &[A::Message] {
+        &self.messages
+    }
+
+    /// Clears the messages processed so far.
+    pub fn clear_messages(&mut self) {
+        self.messages.clear();
+    }
+
+    /// Takes the operations on widgets requested by commands so far, in
+    /// order.
+    ///
+    /// You can run them on a [`UserInterface`] of your own to check their
+    /// effects, or use an [`Interface`], which runs them right away.
+    ///
+    /// [`UserInterface`]: iced_native::UserInterface
+    pub fn take_operations(&mut self) -> Vec<Box<dyn Operation<A::Message>>> {
+        std::mem::take(&mut self.operations)
+    }
+
+    /// Feeds the given message to the [`Application`], processing every
+    /// message produced as a result until the [`Runtime`] settles.
+    ///
+    /// It panics if the [`Runtime`] does not settle after [`MAX_ROUNDS`].
+    pub fn update(&mut self, message: A::Message) {
+        self.process(message);
+        self.settle();
+    }
+
+    /// Broadcasts the given [`Event`] to the subscriptions of the
+    /// [`Application`], as if no widget had captured it, and processes every
+    /// resulting message until the [`Runtime`] settles.
+    ///
+    /// It panics if the [`Runtime`] does not settle after [`MAX_ROUNDS`].
+    pub fn event(&mut self, event: Event) {
+        self.event_with_status(event, event::Status::Ignored);
+    }
+
+    /// Broadcasts the given [`Event`] to the subscriptions of the
+    /// [`Application`] with the given [`event::Status`], and processes every
+    /// resulting message until the [`Runtime`] settles.
+    ///
+    /// It panics if the [`Runtime`] does not settle after [`MAX_ROUNDS`].
+    pub fn event_with_status(&mut self, event: Event, status: event::Status) {
+        self.engine.broadcast(event, status);
+        self.settle();
+    }
+
+    fn process(&mut self, message: A::Message) {
+        self.messages.push(message.clone());
+
+        let command = self.application.update(message);
+
+        self.spawn(command);
+    }
+
+    fn spawn(&mut self, command: iced::Command<A::Message>) {
+        let (futures, operations) = command.into_parts();
+
+        self.engine.spawn(futures);
+        self.operations.extend(operations);
+        self.engine.track(self.application.subscription());
+    }
+
+    fn settle(&mut self) {
+        for _ in 0..MAX_ROUNDS {
+            let messages = self.engine.run_until_stalled();
+
+            if messages.is_empty() {
+                return;
+            }
+
+            for message in messages {
+                self.process(message);
+            }
+        }
+
+        panic!(
+            "The runtime did not settle after {} rounds of messages",
+            MAX_ROUNDS
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced::{executor, Command, Element, Subscription, Text};
+    use iced_native::keyboard::{self, KeyCode, Modifiers};
+    use iced_native::widget::{operation, Id};
+
+    #[derive(Default)]
+    struct Harness {
+        is_listening: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Fetch,
+        Fetched(u32),
+        Listen,
+        KeyPressed(KeyCode),
+        Focus,
+        Loop,
+    }
+
+    impl Application for Harness {
+        type Executor = executor::Default;
+        type Message = Message;
+        type Flags = ();
+
+        fn new(_flags: ()) -> (Harness, Command<Message>) {
+            (
+                Harness::default(),
+                Command::from(async { Message::Fetched(0) }),
+            )
+        }
+
+        fn title(&self) -> String {
+            String::from("Harness")
+        }
+
+        fn update(&mut self, message: Message) -> Command<Message> {
+            match message {
+                Message::Fetch => Command::batch(vec![
+                    Command::from(async { Message::Fetched(1) }),
+                    Command::from(async { Message::Fetched(2) }),
+                ]),
+                Message::Listen => {
+                    self.is_listening = true;
+
+                    Command::none()
+                }
+                Message::Focus => Command::widget(operation::focusable::focus(
+                    Id::new("search"),
+                )),
+                Message::Loop => Command::from(async { Message::Loop }),
+                Message::Fetched(_) | Message::KeyPressed(_) => Command::none(),
+            }
+        }
+
+        fn subscription(&self) -> Subscription<Message> {
+            if !self.is_listening {
+                return Subscription::none();
+            }
+
+            iced_native::subscription::events_with(|event, _status| match event
+            {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code,
+                    ..
+                }) => Some(Message::KeyPressed(key_code)),
+                _ => None,
+            })
+        }
+
+        fn view(&mut self) -> Element<'_, Message> {
+            Text::new("Harness").into()
+        }
+    }
+
+    #[test]
+    fn runs_commands_until_settled() {
+        let mut runtime = Runtime::<Harness>::new(());
+
+        assert_eq!(runtime.messages(), &[Message::Fetched(0)]);
+
+        runtime.clear_messages();
+        runtime.update(Message::Fetch);
+
+        assert_eq!(
+            runtime.messages(),
+            &[Message::Fetch, Message::Fetched(1), Message::Fetched(2)]
+        );
+    }
+
+    #[test]
+    fn feeds_events_to_subscriptions() {
+        let mut runtime = Runtime::<Harness>::new(());
+        let key_pressed = Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: KeyCode::A,
+            modifiers: Modifiers::default(),
+        });
+
+        runtime.event(key_pressed.clone());
+        assert_eq!(runtime.messages(), &[Message::Fetched(0)]);
+
+        runtime.update(Message::Listen);
+        runtime.event(key_pressed);
+        assert_eq!(
+            runtime.messages().last(),
+            Some(&Message::KeyPressed(KeyCode::A))
+        );
+    }
+
+    #[test]
+    fn keeps_operations_until_taken() {
+        let mut runtime = Runtime::<Harness>::new(());
+
+        runtime.update(Message::Focus);
+        runtime.update(Message::Focus);
+
+        assert_eq!(runtime.take_operations().len(), 2);
+        assert!(runtime.take_operations().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "did not settle")]
+    fn gives_up_on_endless_messages() {
+        let mut runtime = Runtime::<Harness>::new(());
+
+        runtime.update(Message::Loop);
+    }
+}
@@ -75,7 +75,7 @@ pub use iced_core::{
     keyboard, mouse, Align, Background, Color, Font, HorizontalAlignment,
     Length, Point, Rectangle, Size, Vector, VerticalAlignment,
 };
-pub use iced_futures::{executor, futures, Command};
+pub use iced_futures::{cancellation, executor, futures, Command};
 pub use subscription::Subscription;
 
 #[doc(no_inline)]
@@ -0,0 +1,11 @@
+//! Show and navigate the path to the current view.
+use crate::Renderer;
+
+pub use iced_graphics::breadcrumbs::{Style, StyleSheet};
+
+/// A path of segments where every segment but the current one can be
+/// selected.
+///
+/// This is an alias of an `iced_native` breadcrumbs with an
+/// `iced_wgpu::Renderer`.
+pub type Breadcrumbs<Message> = iced_native::Breadcrumbs<Message, Renderer>;
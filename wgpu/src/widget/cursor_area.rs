@@ -0,0 +1,9 @@
+//! Show a specific mouse cursor over some content.
+use crate::Renderer;
+
+/// An element that shows a specific mouse cursor while it is hovered.
+///
+/// This is an alias of an `iced_native` cursor area with an
+/// `iced_wgpu::Renderer`.
+pub type CursorArea<'a, Message> =
+    iced_native::CursorArea<'a, Message, Renderer>;
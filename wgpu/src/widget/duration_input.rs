@@ -0,0 +1,14 @@
+//! Enter durations and timestamps, like `01:02:03.456`.
+//!
+//! A [`DurationInput`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::text_input::{Style, StyleSheet};
+pub use iced_native::duration_input::{format, parse, State};
+
+/// A field that can be filled with a duration.
+///
+/// This is an alias of an `iced_native` duration input with an
+/// `iced_wgpu::Renderer`.
+pub type DurationInput<'a, Message> =
+    iced_native::DurationInput<'a, Message, Renderer>;
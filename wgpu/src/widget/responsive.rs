@@ -0,0 +1,13 @@
+//! Build parts of your user interface based on the space they are given.
+//!
+//! A [`Responsive`] widget has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::responsive::State;
+
+/// A widget that produces its contents from the size it is allocated.
+///
+/// This is an alias of an `iced_native` responsive widget with an
+/// `iced_wgpu::Renderer`.
+pub type Responsive<'a, Message> =
+    iced_native::Responsive<'a, Message, Renderer>;
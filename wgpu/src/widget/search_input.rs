@@ -0,0 +1,13 @@
+//! Filter large lists with a text field that debounces its searches.
+//!
+//! A [`SearchInput`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::search_input::{State, Style, StyleSheet};
+
+/// A text input with a search icon and a button to clear it.
+///
+/// This is an alias of an `iced_native` search input with an
+/// `iced_wgpu::Renderer`.
+pub type SearchInput<'a, Message> =
+    iced_native::SearchInput<'a, Message, Renderer>;
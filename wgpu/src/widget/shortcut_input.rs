@@ -0,0 +1,14 @@
+//! Let users rebind keyboard shortcuts by pressing them.
+//!
+//! A [`ShortcutInput`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::shortcut_input::{State, Style, StyleSheet};
+
+/// A field that shows a keyboard shortcut and captures the next one pressed
+/// after clicking it.
+///
+/// This is an alias of an `iced_native` shortcut input with an
+/// `iced_wgpu::Renderer`.
+pub type ShortcutInput<'a, Message> =
+    iced_native::ShortcutInput<'a, Message, Renderer>;
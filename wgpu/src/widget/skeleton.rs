@@ -0,0 +1,10 @@
+//! Hold the place of content that is still loading.
+use crate::Renderer;
+
+pub use iced_graphics::skeleton::{Style, StyleSheet};
+
+/// A placeholder block shown while some content is loading.
+///
+/// This is an alias of an `iced_native` skeleton with an
+/// `iced_wgpu::Renderer`.
+pub type Skeleton = iced_native::Skeleton<Renderer>;
@@ -0,0 +1,9 @@
+//! Switch between views with a row of tabs.
+use crate::Renderer;
+
+pub use iced_graphics::tab_bar::{Style, StyleSheet};
+
+/// A row of tabs, one of which is selected.
+///
+/// This is an alias of an `iced_native` tab bar with an `iced_wgpu::Renderer`.
+pub type TabBar<Message> = iced_native::TabBar<Message, Renderer>;
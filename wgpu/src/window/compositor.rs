@@ -1,6 +1,7 @@
 use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
 use futures::task::SpawnExt;
+use iced_graphics::window::Configuration;
 use iced_native::{futures, mouse};
 use raw_window_handle::HasRawWindowHandle;
 
@@ -8,6 +9,7 @@ use raw_window_handle::HasRawWindowHandle;
 #[allow(missing_debug_implementations)]
 pub struct Compositor {
     settings: Settings,
+    initial_settings: Settings,
     instance: wgpu::Instance,
     device: wgpu::Device,
     queue: wgpu::Queue,
@@ -56,6 +58,7 @@ impl Compositor {
         Some(Compositor {
             instance,
             settings,
+            initial_settings: settings,
             device,
             queue,
             staging_belt,
@@ -112,6 +115,24 @@ impl iced_graphics::window::Compositor for Compositor {
         )
     }
 
+    fn configure(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        configuration: Option<Configuration>,
+    ) {
+        self.settings = match configuration {
+            Some(configuration) => Settings {
+                default_font: configuration.default_font,
+                default_text_size: configuration.default_text_size,
+                antialiasing: configuration.antialiasing,
+                ..self.settings
+            },
+            None => self.initial_settings,
+        };
+
+        *renderer = Renderer::new(self.create_backend());
+    }
+
     fn draw<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
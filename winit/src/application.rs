@@ -14,7 +14,8 @@ use iced_futures::futures;
 use iced_futures::futures::channel::mpsc;
 use iced_graphics::window;
 use iced_native::program::Program;
-use iced_native::{Cache, UserInterface};
+use iced_native::widget::operation::{self, Operation};
+use iced_native::{Cache, Inspector, UserInterface};
 
 use std::mem::ManuallyDrop;
 
@@ -28,7 +29,8 @@ use std::mem::ManuallyDrop;
 /// [`Command`] in some of its methods.
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`, and an inspector that explains the layout
+/// of the widgets under the cursor can be toggled by pressing `Shift+F12`.
 pub trait Application: Program {
     /// The data needed to initialize your [`Application`].
     type Flags;
@@ -91,6 +93,45 @@ pub trait Application: Program {
     fn scale_factor(&self) -> f64 {
         1.0
     }
+
+    /// Returns the renderer [`Configuration`] of the [`Application`].
+    ///
+    /// The runtime will reconfigure the renderer if a new [`Configuration`]
+    /// is returned, which can be used to change the default font, the default
+    /// text size or the antialiasing strategy at runtime.
+    ///
+    /// By default, it returns `None`, which keeps the renderer settings the
+    /// [`Application`] was started with. Returning `None` after a
+    /// [`Configuration`] restores them.
+    ///
+    /// [`Configuration`]: window::Configuration
+    fn configuration(&self) -> Option<window::Configuration> {
+        None
+    }
+
+    /// Returns whether the [`Application`] should exit when the user requests
+    /// its window to close.
+    ///
+    /// If it returns `false`, a [`window::Event::CloseRequested`] will be
+    /// produced instead and it will be up to the [`Application`] to decide
+    /// when to exit, by means of [`should_exit`].
+    ///
+    /// By default, it returns `true`.
+    ///
+    /// [`window::Event::CloseRequested`]: crate::window::Event::CloseRequested
+    /// [`should_exit`]: Self::should_exit
+    fn exit_on_close_request(&self) -> bool {
+        true
+    }
+
+    /// Returns whether the [`Application`] should exit.
+    ///
+    /// The runtime will check it after every update.
+    ///
+    /// By default, it returns `false`.
+    fn should_exit(&self) -> bool {
+        false
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -103,6 +144,7 @@ where
     A: Application + 'static,
     E: Executor + 'static,
     C: window::Compositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: Inspector,
 {
     use futures::task;
     use futures::Future;
@@ -129,10 +171,13 @@ where
     };
 
     let subscription = application.subscription();
+    let (init_futures, init_operations) = init_command.into_parts();
 
-    runtime.spawn(init_command);
+    runtime.spawn(init_futures);
     runtime.track(subscription);
 
+    let position = settings.window.position;
+
     let window = settings
         .window
         .into_builder(
@@ -143,6 +188,10 @@ where
         .build(&event_loop)
         .map_err(Error::WindowCreationFailed)?;
 
+    if let Some((x, y)) = position {
+        window.set_outer_position(winit::dpi::LogicalPosition { x, y });
+    }
+
     let (mut sender, receiver) = mpsc::unbounded();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
@@ -153,6 +202,7 @@ where
         runtime,
         debug,
         receiver,
+        init_operations,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -177,6 +227,9 @@ where
     });
 }
 
+// The instance takes ownership of everything the event loop sets up before
+// it starts running, including the operations of the initial command.
+#[allow(clippy::too_many_arguments)]
 async fn run_instance<A, E, C>(
     mut application: A,
     mut compositor: C,
@@ -185,10 +238,12 @@ async fn run_instance<A, E, C>(
     mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<winit::event::Event<'_, A::Message>>,
+    init_operations: Vec<Box<dyn Operation<A::Message>>>,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
     C: window::Compositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: Inspector,
 {
     use iced_futures::futures::stream::StreamExt;
     use winit::event;
@@ -198,6 +253,12 @@ async fn run_instance<A, E, C>(
 
     let mut state = State::new(&application, &window);
     let mut viewport_version = state.viewport_version();
+    let mut configuration = application.configuration();
+
+    if configuration.is_some() {
+        compositor.configure(&mut renderer, configuration);
+    }
+
     let mut swap_chain = {
         let physical_size = state.physical_size();
 
@@ -216,8 +277,10 @@ async fn run_instance<A, E, C>(
         &mut debug,
     ));
 
+    run_operations(&mut user_interface, init_operations, &mut runtime);
+
     let mut primitive =
-        user_interface.draw(&mut renderer, state.cursor_position());
+        debug.draw(&mut user_interface, &mut renderer, state.cursor_position());
     let mut mouse_interaction = mouse::Interaction::default();
 
     let mut events = Vec::new();
@@ -253,7 +316,7 @@ async fn run_instance<A, E, C>(
                         ManuallyDrop::into_inner(user_interface).into_cache();
 
                     // Update application
-                    update(
+                    let operations = update(
                         &mut application,
                         &mut runtime,
                         &mut debug,
@@ -263,6 +326,17 @@ async fn run_instance<A, E, C>(
                     // Update window
                     state.synchronize(&application, &window);
 
+                    // Update renderer
+                    let new_configuration = application.configuration();
+
+                    if new_configuration != configuration {
+                        compositor.configure(&mut renderer, new_configuration);
+
+                        configuration = new_configuration;
+                    }
+
+                    let should_exit = application.should_exit();
+
                     user_interface = ManuallyDrop::new(build_user_interface(
                         &mut application,
                         cache,
@@ -270,11 +344,24 @@ async fn run_instance<A, E, C>(
                         state.logical_size(),
                         &mut debug,
                     ));
+
+                    run_operations(
+                        &mut user_interface,
+                        operations,
+                        &mut runtime,
+                    );
+
+                    if should_exit {
+                        break;
+                    }
                 }
 
                 debug.draw_started();
-                primitive =
-                    user_interface.draw(&mut renderer, state.cursor_position());
+                primitive = debug.draw(
+                    &mut user_interface,
+                    &mut renderer,
+                    state.cursor_position(),
+                );
                 debug.draw_finished();
 
                 window.request_redraw();
@@ -298,8 +385,11 @@ async fn run_instance<A, E, C>(
                     debug.layout_finished();
 
                     debug.draw_started();
-                    primitive = user_interface
-                        .draw(&mut renderer, state.cursor_position());
+                    primitive = debug.draw(
+                        &mut user_interface,
+                        &mut renderer,
+                        state.cursor_position(),
+                    );
                     debug.draw_finished();
 
                     swap_chain = compositor.create_swap_chain(
@@ -323,6 +413,9 @@ async fn run_instance<A, E, C>(
                 debug.render_finished();
 
                 if new_mouse_interaction != mouse_interaction {
+                    window.set_cursor_visible(
+                        new_mouse_interaction != mouse::Interaction::Hidden,
+                    );
                     window.set_cursor_icon(conversion::mouse_interaction(
                         new_mouse_interaction,
                     ));
@@ -337,7 +430,9 @@ async fn run_instance<A, E, C>(
                 event: window_event,
                 ..
             } => {
-                if requests_exit(&window_event, state.modifiers()) {
+                if requests_exit(&window_event, state.modifiers())
+                    && state.exit_on_close_request()
+                {
                     break;
                 }
 
@@ -405,12 +500,17 @@ pub fn build_user_interface<'a, A: Application>(
 
 /// Updates an [`Application`] by feeding it the provided messages, spawning any
 /// resulting [`Command`], and tracking its [`Subscription`].
+///
+/// It returns the operations on widgets of the resulting commands, which
+/// should be run with [`run_operations`] once the user interface is rebuilt.
 pub fn update<A: Application, E: Executor>(
     application: &mut A,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
-) {
+) -> Vec<Box<dyn Operation<A::Message>>> {
+    let mut operations = Vec::new();
+
     for message in messages.drain(..) {
         debug.log_message(&message);
 
@@ -418,9 +518,46 @@ pub fn update<A: Application, E: Executor>(
         let command = runtime.enter(|| application.update(message));
         debug.update_finished();
 
-        runtime.spawn(command);
+        let (futures, command_operations) = command.into_parts();
+
+        runtime.spawn(futures);
+        operations.extend(command_operations);
     }
 
     let subscription = application.subscription();
     runtime.track(subscription);
+
+    operations
+}
+
+/// Runs the given operations on the widgets of a [`UserInterface`], feeding
+/// any message they produce back to the [`Runtime`].
+pub fn run_operations<Message, Renderer, E>(
+    user_interface: &mut UserInterface<'_, Message, Renderer>,
+    operations: Vec<Box<dyn Operation<Message>>>,
+    runtime: &mut Runtime<E, Proxy<Message>, Message>,
+) where
+    Message: 'static + Send,
+    Renderer: iced_native::Renderer,
+    E: Executor,
+{
+    for mut operation in operations {
+        loop {
+            user_interface.operate(operation.as_mut());
+
+            match operation.finish() {
+                operation::Outcome::None => break,
+                operation::Outcome::Some(message) => {
+                    runtime.spawn(iced_futures::Command::from(
+                        futures::future::ready(message),
+                    ));
+
+                    break;
+                }
+                operation::Outcome::Chain(next) => {
+                    operation = next;
+                }
+            }
+        }
+    }
 }
@@ -12,6 +12,7 @@ pub struct State<A: Application> {
     mode: Mode,
     background_color: Color,
     scale_factor: f64,
+    exit_on_close_request: bool,
     viewport: Viewport,
     viewport_version: usize,
     cursor_position: winit::dpi::PhysicalPosition<f64>,
@@ -26,6 +27,7 @@ impl<A: Application> State<A> {
         let mode = application.mode();
         let background_color = application.background_color();
         let scale_factor = application.scale_factor();
+        let exit_on_close_request = application.exit_on_close_request();
 
         let viewport = {
             let physical_size = window.inner_size();
@@ -41,6 +43,7 @@ impl<A: Application> State<A> {
             mode,
             background_color,
             scale_factor,
+            exit_on_close_request,
             viewport,
             viewport_version: 0,
             // TODO: Encode cursor availability in the type-system
@@ -55,6 +58,12 @@ impl<A: Application> State<A> {
         self.background_color
     }
 
+    /// Returns whether the [`Application`] should exit when its window is
+    /// requested to close.
+    pub fn exit_on_close_request(&self) -> bool {
+        self.exit_on_close_request
+    }
+
     /// Returns the current [`Viewport`] of the [`State`].
     pub fn viewport(&self) -> &Viewport {
         &self.viewport
@@ -148,7 +157,13 @@ impl<A: Application> State<A> {
                         ..
                     },
                 ..
-            } => _debug.toggle(),
+            } => {
+                if self.modifiers.shift() {
+                    _debug.toggle_inspector();
+                } else {
+                    _debug.toggle();
+                }
+            }
             _ => {}
         }
     }
@@ -185,6 +200,9 @@ impl<A: Application> State<A> {
         // Update background color
         self.background_color = application.background_color();
 
+        // Update exit behavior
+        self.exit_on_close_request = application.exit_on_close_request();
+
         // Update scale factor
         let new_scale_factor = application.scale_factor();
 
@@ -32,6 +32,17 @@ pub fn window_event(
                 height: logical_size.height,
             }))
         }
+        WindowEvent::CloseRequested => {
+            Some(Event::Window(window::Event::CloseRequested))
+        }
+        WindowEvent::Moved(position) => {
+            let logical_position = position.to_logical(scale_factor);
+
+            Some(Event::Window(window::Event::Moved {
+                x: logical_position.x,
+                y: logical_position.y,
+            }))
+        }
         WindowEvent::CursorMoved { position, .. } => {
             let position = position.to_logical::<f64>(scale_factor);
 
@@ -158,6 +169,13 @@ pub fn mouse_interaction(
             winit::window::CursorIcon::EwResize
         }
         Interaction::ResizingVertically => winit::window::CursorIcon::NsResize,
+        Interaction::NotAllowed => winit::window::CursorIcon::NotAllowed,
+        Interaction::Move => winit::window::CursorIcon::Move,
+        Interaction::ZoomIn => winit::window::CursorIcon::ZoomIn,
+        Interaction::ZoomOut => winit::window::CursorIcon::ZoomOut,
+        Interaction::Help => winit::window::CursorIcon::Help,
+        // Hiding the cursor is handled by the window itself
+        Interaction::Hidden => winit::window::CursorIcon::Default,
     }
 }
 
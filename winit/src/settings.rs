@@ -31,6 +31,14 @@ pub struct Window {
     /// The size of the window.
     pub size: (u32, u32),
 
+    /// The initial logical position of the window.
+    ///
+    /// If `None`, the platform decides where to place the window.
+    pub position: Option<(i32, i32)>,
+
+    /// Whether the window should start maximized or not.
+    pub maximized: bool,
+
     /// The minimum size of the window.
     pub min_size: Option<(u32, u32)>,
 
@@ -72,6 +80,7 @@ impl Window {
             .with_title(title)
             .with_inner_size(winit::dpi::LogicalSize { width, height })
             .with_resizable(self.resizable)
+            .with_maximized(self.maximized)
             .with_decorations(self.decorations)
             .with_transparent(self.transparent)
             .with_window_icon(self.icon)
@@ -105,6 +114,8 @@ impl Default for Window {
     fn default() -> Window {
         Window {
             size: (1024, 768),
+            position: None,
+            maximized: false,
             min_size: None,
             max_size: None,
             resizable: true,